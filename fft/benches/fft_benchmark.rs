@@ -0,0 +1,97 @@
+use ark_bn254::Fr;
+use ark_ff::FftField;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use fft::{fft::dft, fft_domain::FftDomain};
+use small_fields::{babybear::BabyBear, goldilocks::Goldilocks};
+
+/// Sizes from 2^10 to 2^22, stepping by a factor of 16 to keep the run time reasonable.
+const LOG_SIZES: [u32; 4] = [10, 14, 18, 22];
+
+/// Compares the recursive `dft`, the in-place iterative NTT of `FftDomain`, the six-step NTT and
+/// `ark_poly`'s radix-2 domain on the same coefficient vector. Run with `--features parallel` to
+/// time the six-step NTT across threads.
+pub fn ntt_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("NTT");
+    group.sample_size(10);
+
+    for log_size in LOG_SIZES {
+        let n = 1usize << log_size;
+        let coeffs: Vec<Fr> = (0..n).map(|i| Fr::from(i as u64)).collect();
+
+        let root = Fr::get_root_of_unity(n as u64).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("recursive dft", n),
+            &coeffs,
+            |b, coeffs| b.iter(|| black_box(dft(black_box(coeffs), root))),
+        );
+
+        // Twiddles are built once per domain, outside the timed loop.
+        let domain = FftDomain::<Fr>::new(n).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("iterative ntt", n),
+            &coeffs,
+            |b, coeffs| {
+                b.iter(|| {
+                    let mut values = coeffs.clone();
+                    domain.fft_in_place(black_box(&mut values));
+                    black_box(values);
+                })
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("six-step ntt", n), &coeffs, |b, coeffs| {
+            b.iter(|| {
+                let mut values = coeffs.clone();
+                domain.six_step_fft_in_place(black_box(&mut values));
+                black_box(values);
+            })
+        });
+
+        let ark_domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        group.bench_with_input(BenchmarkId::new("ark_poly", n), &coeffs, |b, coeffs| {
+            b.iter(|| {
+                let mut values = coeffs.clone();
+                ark_domain.fft_in_place(black_box(&mut values));
+                black_box(values);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_field_ntt<F: FftField>(group: &mut BenchmarkGroup<WallTime>, name: &str, log_size: u32) {
+    let n = 1usize << log_size;
+    let coeffs: Vec<F> = (0..n).map(|i| F::from(i as u64)).collect();
+    let domain = FftDomain::<F>::new(n).unwrap();
+
+    group.bench_with_input(BenchmarkId::new(name, n), &coeffs, |b, coeffs| {
+        b.iter(|| {
+            let mut values = coeffs.clone();
+            domain.fft_in_place(black_box(&mut values));
+            black_box(values);
+        })
+    });
+}
+
+/// Runs the iterative NTT over BN254's scalar field and the small hash-friendly fields, whose
+/// single-limb Montgomery arithmetic is several times cheaper per butterfly.
+pub fn field_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("NTT by field");
+    group.sample_size(10);
+
+    for log_size in [14, 18] {
+        bench_field_ntt::<Fr>(&mut group, "bn254", log_size);
+        bench_field_ntt::<Goldilocks>(&mut group, "goldilocks", log_size);
+        bench_field_ntt::<BabyBear>(&mut group, "babybear", log_size);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ntt_benchmark, field_benchmark);
+criterion_main!(benches);
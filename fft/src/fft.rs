@@ -1,9 +1,9 @@
-use ark_bn254::Fr;
 use ark_ff::{FftField, Field};
 use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
-use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
-fn dft(values: &[Fr], root: Fr) -> Vec<Fr> {
+use crate::fft_domain::{FftDomain, FftError};
+
+pub fn dft<F: FftField>(values: &[F], root: F) -> Vec<F> {
     let n = values.len();
 
     if n == 1 {
@@ -17,7 +17,7 @@ fn dft(values: &[Fr], root: Fr) -> Vec<Fr> {
     let y_even = dft(&even, root_sq);
     let y_odd = dft(&odd, root_sq);
 
-    let mut y = vec![Fr::from(0); n];
+    let mut y = vec![F::zero(); n];
 
     for j in 0..n / 2 {
         let twiddle = root.pow([j as u64]);
@@ -28,38 +28,24 @@ fn dft(values: &[Fr], root: Fr) -> Vec<Fr> {
     y
 }
 
-fn fft_evaluate(poly: &UnivariatePoly<Fr>) -> Vec<Fr> {
-    let n = poly.coefficient.len();
-
-    if !n.is_power_of_two() {
-        panic!("Length must be a power of 2");
-    }
+/// Evaluates the polynomial with coefficients `coeffs` on the smallest domain that fits them,
+/// zero-padding up to the domain size. Returns the evaluations along with the domain they live on.
+pub fn fft_evaluate<F: FftField>(coeffs: &[F]) -> Result<(Vec<F>, FftDomain<F>), FftError> {
+    let domain = FftDomain::new_at_least(coeffs.len())?;
 
-    let omega = Fr::get_root_of_unity(n as u64).unwrap();
-
-    dft(&poly.coefficient, omega)
+    Ok((domain.fft(coeffs), domain))
 }
 
-fn fft_interpolate(evaluations: &[Fr]) -> UnivariatePoly<Fr> {
-    let n = evaluations.len();
-
-    if !n.is_power_of_two() {
-        panic!("Length must be a power of 2");
-    }
-
-    let omega_inv = Fr::get_root_of_unity(n as u64).unwrap().inverse().unwrap();
-
-    let inv_n = Fr::from(n as u64).inverse().unwrap();
-
-    let mut coeffs = dft(evaluations, omega_inv);
+/// Interpolates evaluations over the smallest domain that fits them. Missing evaluations are
+/// taken to be zero, i.e. the result vanishes on the domain points past `evaluations.len()`.
+/// Returns the coefficients along with the domain.
+pub fn fft_interpolate<F: FftField>(evaluations: &[F]) -> Result<(Vec<F>, FftDomain<F>), FftError> {
+    let domain = FftDomain::new_at_least(evaluations.len())?;
 
-    for coeff in &mut coeffs {
-        *coeff *= inv_n;
-    }
-    UnivariatePoly::new(coeffs)
+    Ok((domain.ifft(evaluations), domain))
 }
 
-fn split_poly(poly: &[Fr]) -> (Vec<Fr>, Vec<Fr>) {
+pub fn split_poly<F: Field>(poly: &[F]) -> (Vec<F>, Vec<F>) {
     let poly_even = poly.iter().step_by(2).copied().collect();
 
     let poly_odd = poly.iter().skip(1).step_by(2).copied().collect();
@@ -67,8 +53,8 @@ fn split_poly(poly: &[Fr]) -> (Vec<Fr>, Vec<Fr>) {
     (poly_even, poly_odd)
 }
 
-fn get_interpolation_roots(n: usize) -> Vec<Fr> {
-    let domain = GeneralEvaluationDomain::<Fr>::new(n).unwrap();
+pub fn get_interpolation_roots<F: FftField>(n: usize) -> Vec<F> {
+    let domain = GeneralEvaluationDomain::<F>::new(n).unwrap();
 
     let omega_inv = domain.group_gen_inv();
 
@@ -81,9 +67,10 @@ fn get_interpolation_roots(n: usize) -> Vec<Fr> {
 mod test {
     use ark_bn254::Fr;
     use ark_ff::{FftField, Field};
+    use small_fields::goldilocks::Goldilocks2;
     use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
-    use super::{fft_evaluate, fft_interpolate, split_poly};
+    use super::{fft_evaluate, fft_interpolate, get_interpolation_roots, split_poly};
 
     #[test]
     fn test_splits_poly_correctly() {
@@ -104,9 +91,7 @@ mod test {
 
     fn test_evaluates_poly() {
         let coefficients = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)];
-        let poly = UnivariatePoly::new(coefficients);
-
-        let evaluations = fft_evaluate(&poly);
+        let (evaluations, _) = fft_evaluate(&coefficients).unwrap();
 
         let n = 4;
         let omega = Fr::get_root_of_unity(n as u64).unwrap();
@@ -128,12 +113,76 @@ mod test {
     fn test_interpolates_poly() {
         let coeffs = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)];
 
+        let (evaluations, _) = fft_evaluate(&coeffs).unwrap();
+
+        let (interpolated, _) = fft_interpolate(&evaluations).unwrap();
+
+        assert_eq!(interpolated, coeffs);
+    }
+
+    #[test]
+    fn test_round_trips_over_an_extension_field() {
+        let coeffs: Vec<Goldilocks2> = (1..=6)
+            .map(|i| Goldilocks2::new(i.into(), (7 * i).into()))
+            .collect();
+
+        let (evaluations, domain) = fft_evaluate(&coeffs).unwrap();
+        let (interpolated, _) = fft_interpolate(&evaluations).unwrap();
+
+        assert_eq!(domain.size, 8);
+        assert_eq!(interpolated[..6], coeffs);
+    }
+
+    #[test]
+    fn test_pads_non_power_of_two_inputs() {
+        let coeffs = vec![
+            Fr::from(1),
+            Fr::from(2),
+            Fr::from(3),
+            Fr::from(4),
+            Fr::from(5),
+        ];
         let poly = UnivariatePoly::new(coeffs.clone());
 
-        let evaluations = fft_evaluate(&poly);
+        let (evaluations, domain) = fft_evaluate(&coeffs).unwrap();
+
+        assert_eq!(domain.size, 8);
+        assert_eq!(evaluations.len(), 8);
+
+        for (x, eval) in domain.elements().iter().zip(evaluations.iter()) {
+            assert_eq!(poly.evaluate(*x), *eval);
+        }
+
+        let (interpolated, _) = fft_interpolate(&evaluations).unwrap();
+
+        assert_eq!(interpolated[..5], coeffs);
+        assert!(interpolated[5..].iter().all(|c| *c == Fr::from(0)));
+    }
+
+    #[test]
+    fn test_interpolates_missing_evaluations_as_zero() {
+        let evaluations = vec![Fr::from(4), Fr::from(7), Fr::from(1)];
+
+        let (coeffs, domain) = fft_interpolate(&evaluations).unwrap();
+        let poly = UnivariatePoly::new(coeffs);
+
+        assert_eq!(domain.size, 4);
+        assert_eq!(poly.evaluate(domain.element(0)), Fr::from(4));
+        assert_eq!(poly.evaluate(domain.element(1)), Fr::from(7));
+        assert_eq!(poly.evaluate(domain.element(2)), Fr::from(1));
+        assert_eq!(poly.evaluate(domain.element(3)), Fr::from(0));
+    }
+
+    #[test]
+    fn test_gets_interpolation_roots() {
+        let n = 4;
+        let omega_inv = Fr::get_root_of_unity(n as u64).unwrap().inverse().unwrap();
+        let inv_n = Fr::from(n as u64).inverse().unwrap();
 
-        let interpolated_poly = fft_interpolate(&evaluations);
+        let roots: Vec<Fr> = get_interpolation_roots(n);
 
-        assert_eq!(interpolated_poly.coefficient, coeffs);
+        for (k, root) in roots.iter().enumerate() {
+            assert_eq!(*root, omega_inv.pow([k as u64]) * inv_n);
+        }
     }
 }
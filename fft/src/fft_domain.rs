@@ -0,0 +1,472 @@
+use ark_ff::FftField;
+use std::{error::Error, fmt};
+
+use crate::{
+    mixed_radix::{mixed_radix_dft, smallest_mixed_size},
+    ntt::{
+        bit_reverse_permutation, cooley_tukey_in_place, gentleman_sande_in_place, twiddle_table,
+    },
+    six_step::six_step_ntt,
+};
+
+/// A multiplicative subgroup `H = {1, w, w^2, ..., w^(n-1)}` with forward and inverse NTT over
+/// it. Power-of-two sizes use the in-place radix-2 NTT, whose twiddle tables are computed once
+/// here and reused by every transform over the domain. Fields with a small subgroup base `q`
+/// also get domains of size `2^a * q^b`, transformed with the mixed-radix DFT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FftDomain<F: FftField> {
+    pub size: usize,
+    pub generator: F,
+    pub generator_inv: F,
+    pub size_inv: F,
+    twiddles: Vec<F>,
+    inverse_twiddles: Vec<F>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FftError {
+    NoRootOfUnity(usize),
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FftError::NoRootOfUnity(size) => {
+                write!(f, "field has no root of unity of order {size}")
+            }
+        }
+    }
+}
+
+impl Error for FftError {}
+
+impl<F: FftField> FftDomain<F> {
+    pub fn new(size: usize) -> Result<Self, FftError> {
+        let generator = F::get_root_of_unity(size as u64).ok_or(FftError::NoRootOfUnity(size))?;
+        let generator_inv = generator.inverse().ok_or(FftError::NoRootOfUnity(size))?;
+
+        let (twiddles, inverse_twiddles) = if size.is_power_of_two() {
+            (
+                twiddle_table(generator, size),
+                twiddle_table(generator_inv, size),
+            )
+        } else {
+            (vec![], vec![])
+        };
+
+        Ok(Self {
+            size,
+            generator,
+            generator_inv,
+            size_inv: F::from(size as u64)
+                .inverse()
+                .ok_or(FftError::NoRootOfUnity(size))?,
+            twiddles,
+            inverse_twiddles,
+        })
+    }
+
+    /// Smallest domain that fits `len` values: the next power of two when the field's
+    /// two-adicity allows it, otherwise the smallest mixed-radix size `2^a * q^b`.
+    pub fn new_at_least(len: usize) -> Result<Self, FftError> {
+        let len = len.max(1);
+
+        Self::new(len.next_power_of_two()).or_else(|_| {
+            let size = smallest_mixed_size::<F>(len).ok_or(FftError::NoRootOfUnity(len))?;
+
+            Self::new(size)
+        })
+    }
+
+    pub fn element(&self, i: usize) -> F {
+        self.generator.pow([i as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = F::one();
+
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+
+        elements
+    }
+
+    /// Evaluates the polynomial with the given coefficients on every element of the domain.
+    /// Missing high coefficients are treated as zero.
+    pub fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut evals = self.pad(coeffs);
+        self.fft_in_place(&mut evals);
+
+        evals
+    }
+
+    /// Recovers the coefficients from evaluations over the domain.
+    pub fn ifft(&self, evals: &[F]) -> Vec<F> {
+        let mut coeffs = self.pad(evals);
+        self.ifft_in_place(&mut coeffs);
+
+        coeffs
+    }
+
+    pub fn fft_in_place(&self, values: &mut [F]) {
+        self.check_len(values);
+
+        if !self.size.is_power_of_two() {
+            values.copy_from_slice(&mixed_radix_dft(values, self.generator));
+            return;
+        }
+
+        gentleman_sande_in_place(values, &self.twiddles);
+        bit_reverse_permutation(values);
+    }
+
+    pub fn ifft_in_place(&self, values: &mut [F]) {
+        self.check_len(values);
+
+        if self.size.is_power_of_two() {
+            bit_reverse_permutation(values);
+            cooley_tukey_in_place(values, &self.inverse_twiddles);
+        } else {
+            values.copy_from_slice(&mixed_radix_dft(values, self.generator_inv));
+        }
+
+        for value in values.iter_mut() {
+            *value *= self.size_inv;
+        }
+    }
+
+    /// Same result as `fft_in_place`, computed with the cache-friendly six-step decomposition.
+    /// Only defined for power-of-two domains; pays off from around `2^20` points, especially
+    /// with the `parallel` feature.
+    pub fn six_step_fft_in_place(&self, values: &mut [F]) {
+        self.check_len(values);
+
+        six_step_ntt(values, self.generator);
+    }
+
+    pub fn six_step_ifft_in_place(&self, values: &mut [F]) {
+        self.check_len(values);
+
+        six_step_ntt(values, self.generator_inv);
+
+        for value in values.iter_mut() {
+            *value *= self.size_inv;
+        }
+    }
+
+    /// Evaluates on the coset `offset * H` instead of `H`, by scaling coefficient `i` by
+    /// `offset^i` before the transform.
+    pub fn coset_fft(&self, coeffs: &[F], offset: F) -> Vec<F> {
+        let mut values = self.pad(coeffs);
+        distribute_powers(&mut values, offset);
+        self.fft_in_place(&mut values);
+
+        values
+    }
+
+    /// Recovers the coefficients from evaluations over the coset `offset * H`.
+    pub fn coset_ifft(&self, evals: &[F], offset: F) -> Vec<F> {
+        let mut values = self.pad(evals);
+        self.ifft_in_place(&mut values);
+        distribute_powers(&mut values, offset.inverse().unwrap());
+
+        values
+    }
+
+    fn check_len(&self, values: &[F]) {
+        if values.len() != self.size {
+            panic!("Input length must match the domain size");
+        }
+    }
+
+    fn pad(&self, values: &[F]) -> Vec<F> {
+        if values.len() > self.size {
+            panic!("Input is larger than the domain");
+        }
+
+        let mut padded = values.to_vec();
+        padded.resize(self.size, F::zero());
+
+        padded
+    }
+}
+
+/// Low-degree extension: takes the evaluations of a polynomial on a subgroup of size `n` and
+/// returns its evaluations on the coset `g * H'`, where `H'` has size `n * blowup` and `g` is the
+/// field's multiplicative generator. The coset keeps the extended domain disjoint from the
+/// original one, as FRI and STARK quotients require.
+pub fn lde<F: FftField>(evals: &[F], blowup: usize) -> Vec<F> {
+    if !blowup.is_power_of_two() {
+        panic!("Blowup factor must be a power of 2");
+    }
+
+    let domain = FftDomain::new(evals.len()).expect("Evaluations must span an FFT domain");
+    let extended_domain =
+        FftDomain::new(evals.len() * blowup).expect("Field has no domain of the extended size");
+
+    let coeffs = domain.ifft(evals);
+
+    extended_domain.coset_fft(&coeffs, F::GENERATOR)
+}
+
+fn distribute_powers<F: FftField>(values: &mut [F], offset: F) {
+    let mut power = F::one();
+
+    for value in values.iter_mut() {
+        *value *= power;
+        power *= offset;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lde, FftDomain, FftError};
+    use crate::fft::dft;
+    use ark_bls12_381::Fr as BlsFr;
+    use ark_bn254::Fr;
+    use ark_ff::{
+        fields::{Fp64, MontBackend, MontConfig},
+        FftField, Field,
+    };
+    use small_fields::{babybear::BabyBear, goldilocks::Goldilocks, mersenne31::Mersenne31};
+    use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
+
+    // p - 1 = 2^2 * 3^10 * 1000133, too little two-adicity for any radix-2 domain beyond 4.
+    #[derive(MontConfig)]
+    #[modulus = "236227414069"]
+    #[generator = "6"]
+    #[small_subgroup_base = "3"]
+    #[small_subgroup_power = "10"]
+    struct SmallTwoAdicityConfig;
+    type SmallTwoAdicityField = Fp64<MontBackend<SmallTwoAdicityConfig, 1>>;
+
+    #[test]
+    fn it_rejects_sizes_without_a_root_of_unity() {
+        let domain = FftDomain::<Fr>::new(8).unwrap();
+
+        assert_eq!(domain.generator.pow([8]), Fr::from(1));
+        assert_ne!(domain.generator.pow([4]), Fr::from(1));
+        assert_eq!(FftDomain::<Fr>::new(7), Err(FftError::NoRootOfUnity(7)));
+
+        let too_large = 1 << (Fr::TWO_ADICITY + 1);
+        assert_eq!(
+            FftDomain::<Fr>::new(too_large),
+            Err(FftError::NoRootOfUnity(too_large))
+        );
+        // Past the two-adic limit, BN254's 3-adic subgroup still provides a 9 * 2^26 domain.
+        assert_eq!(
+            FftDomain::<Fr>::new_at_least(too_large - 1).unwrap().size,
+            9 << 26
+        );
+    }
+
+    fn agrees_with_the_six_step_ntt<F: FftField>() {
+        let domain = FftDomain::<F>::new(1 << 12).unwrap();
+        let coeffs: Vec<F> = (0..domain.size)
+            .map(|i| F::from(i as u64 * 7 + 3))
+            .collect();
+
+        let mut radix_2 = coeffs.clone();
+        domain.fft_in_place(&mut radix_2);
+
+        let mut six_step = coeffs.clone();
+        domain.six_step_fft_in_place(&mut six_step);
+        assert_eq!(six_step, radix_2);
+
+        domain.six_step_ifft_in_place(&mut six_step);
+        assert_eq!(six_step, coeffs);
+    }
+
+    #[test]
+    fn it_pads_to_the_next_power_of_two() {
+        assert_eq!(FftDomain::<Fr>::new_at_least(5).unwrap().size, 8);
+        assert_eq!(FftDomain::<Fr>::new_at_least(8).unwrap().size, 8);
+        assert_eq!(FftDomain::<Fr>::new_at_least(0).unwrap().size, 1);
+    }
+
+    #[test]
+    fn it_falls_back_to_mixed_radix_for_low_two_adicity() {
+        // 2-adicity 2 and 3-adicity 10: a length 5 input gets a size 6 domain instead of 8.
+        let domain = FftDomain::<SmallTwoAdicityField>::new_at_least(5).unwrap();
+        assert_eq!(domain.size, 6);
+
+        let coeffs: Vec<SmallTwoAdicityField> =
+            (0..5).map(|i| SmallTwoAdicityField::from(i + 2)).collect();
+        let evals = domain.fft(&coeffs);
+
+        for (x, eval) in domain.elements().iter().zip(evals.iter()) {
+            let expected: SmallTwoAdicityField = coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, c)| *c * x.pow([i as u64]))
+                .sum();
+
+            assert_eq!(*eval, expected);
+        }
+
+        let mut round_trip = domain.ifft(&evals);
+        round_trip.truncate(5);
+
+        assert_eq!(round_trip, coeffs);
+        assert_eq!(
+            FftDomain::<SmallTwoAdicityField>::new_at_least(200)
+                .unwrap()
+                .size,
+            243
+        );
+    }
+
+    #[test]
+    fn it_errors_without_a_suitable_root_of_unity() {
+        let too_large = 4 * 3usize.pow(10) + 1;
+
+        assert_eq!(
+            FftDomain::<SmallTwoAdicityField>::new_at_least(too_large),
+            Err(FftError::NoRootOfUnity(too_large))
+        );
+    }
+
+    fn evaluates_on_the_domain<F: FftField>(size: usize) {
+        let coeffs: Vec<F> = (1..=size).map(|i| F::from(i as u64)).collect();
+        let poly = UnivariatePoly::new(coeffs.clone());
+        let domain = FftDomain::new(size).unwrap();
+
+        let expected: Vec<F> = domain
+            .elements()
+            .iter()
+            .map(|x| poly.evaluate(*x))
+            .collect();
+
+        assert_eq!(domain.fft(&coeffs), expected);
+        assert_eq!(domain.ifft(&expected), coeffs);
+    }
+
+    #[test]
+    fn it_pads_short_inputs() {
+        let coeffs = vec![Fr::from(3), Fr::from(5)];
+        let poly = UnivariatePoly::new(coeffs.clone());
+        let domain = FftDomain::new(4).unwrap();
+
+        let expected: Vec<Fr> = domain
+            .elements()
+            .iter()
+            .map(|x| poly.evaluate(*x))
+            .collect();
+
+        assert_eq!(domain.fft(&coeffs), expected);
+    }
+
+    fn transforms_in_place<F: FftField>() {
+        let coeffs: Vec<F> = (0..32u64).map(|i| F::from(3 * i + 1)).collect();
+        let domain = FftDomain::new(32).unwrap();
+
+        let mut values = coeffs.clone();
+        domain.fft_in_place(&mut values);
+
+        assert_eq!(values, dft(&coeffs, domain.generator));
+
+        domain.ifft_in_place(&mut values);
+
+        assert_eq!(values, coeffs);
+    }
+
+    #[test]
+    fn it_round_trips_over_another_field() {
+        let coeffs: Vec<BlsFr> = (0..16).map(|i| BlsFr::from(i * i + 1)).collect();
+        let domain = FftDomain::new(16).unwrap();
+
+        let evals = domain.fft(&coeffs);
+
+        assert_eq!(domain.ifft(&evals), coeffs);
+    }
+
+    fn evaluates_on_a_coset<F: FftField>(size: usize) {
+        let coeffs: Vec<F> = (0..size as u64).map(|i| F::from(i * i + 2)).collect();
+        let poly = UnivariatePoly::new(coeffs.clone());
+        let domain = FftDomain::new(size).unwrap();
+        let offset = F::from(7u64);
+
+        let expected: Vec<F> = domain
+            .elements()
+            .iter()
+            .map(|x| poly.evaluate(offset * x))
+            .collect();
+
+        let evals = domain.coset_fft(&coeffs, offset);
+
+        assert_eq!(evals, expected);
+        assert_eq!(domain.coset_ifft(&evals, offset), coeffs);
+    }
+
+    fn extends_to_a_larger_coset<F: FftField>() {
+        let coeffs: Vec<F> = (0..4u64).map(|i| F::from(5 * i + 1)).collect();
+        let poly = UnivariatePoly::new(coeffs.clone());
+        let domain = FftDomain::new(4).unwrap();
+
+        let extension = lde(&domain.fft(&coeffs), 4);
+
+        let extended_domain = FftDomain::<F>::new(16).unwrap();
+        let expected: Vec<F> = extended_domain
+            .elements()
+            .iter()
+            .map(|x| poly.evaluate(F::GENERATOR * x))
+            .collect();
+
+        assert_eq!(extension, expected);
+    }
+
+    #[test]
+    fn it_agrees_with_the_six_step_ntt() {
+        agrees_with_the_six_step_ntt::<Fr>();
+    }
+
+    #[test]
+    fn it_evaluates_on_the_domain() {
+        evaluates_on_the_domain::<Fr>(8);
+    }
+
+    #[test]
+    fn it_transforms_in_place() {
+        transforms_in_place::<Fr>();
+    }
+
+    #[test]
+    fn it_evaluates_on_a_coset() {
+        evaluates_on_a_coset::<Fr>(8);
+    }
+
+    #[test]
+    fn it_extends_to_a_larger_coset() {
+        extends_to_a_larger_coset::<Fr>();
+    }
+
+    #[test]
+    fn it_runs_over_small_fields() {
+        agrees_with_the_six_step_ntt::<Goldilocks>();
+        agrees_with_the_six_step_ntt::<BabyBear>();
+
+        transforms_in_place::<Goldilocks>();
+        transforms_in_place::<BabyBear>();
+
+        evaluates_on_the_domain::<Goldilocks>(64);
+        evaluates_on_the_domain::<BabyBear>(64);
+        // Mersenne31 only has the 2 * 3^2 subgroup, reached through the mixed-radix path.
+        evaluates_on_the_domain::<Mersenne31>(18);
+
+        evaluates_on_a_coset::<Goldilocks>(8);
+        evaluates_on_a_coset::<BabyBear>(8);
+        evaluates_on_a_coset::<Mersenne31>(9);
+
+        extends_to_a_larger_coset::<Goldilocks>();
+        extends_to_a_larger_coset::<BabyBear>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_doesnt_extend_by_a_non_power_of_two() {
+        let _ = lde(&[Fr::from(1), Fr::from(2)], 3);
+    }
+}
@@ -1 +1,5 @@
 pub mod fft;
+pub mod fft_domain;
+pub mod mixed_radix;
+pub mod ntt;
+pub mod six_step;
@@ -0,0 +1,79 @@
+use ark_ff::FftField;
+
+/// Recursive decimation-in-time DFT for sizes of the form `2^a * q^b`, where `q` is the field's
+/// small subgroup base. Each level splits on the smallest radix dividing the current size, so
+/// power-of-two sizes fall back to the usual radix-2 recursion.
+pub fn mixed_radix_dft<F: FftField>(values: &[F], root: F) -> Vec<F> {
+    let n = values.len();
+
+    if n == 1 {
+        return values.to_vec();
+    }
+
+    let radix = smallest_radix::<F>(n);
+    let sub_size = n / radix;
+    let sub_root = root.pow([radix as u64]);
+
+    let sub_transforms: Vec<Vec<F>> = (0..radix)
+        .map(|j| {
+            let subsequence: Vec<F> = values.iter().skip(j).step_by(radix).copied().collect();
+
+            mixed_radix_dft(&subsequence, sub_root)
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(n);
+    let mut root_k = F::one();
+
+    for k in 0..n {
+        // X[k] = sum_j root^(j * k) * Y_j[k mod sub_size], evaluated with Horner in root^k.
+        let mut acc = F::zero();
+
+        for sub_transform in sub_transforms.iter().rev() {
+            acc = acc * root_k + sub_transform[k % sub_size];
+        }
+
+        result.push(acc);
+        root_k *= root;
+    }
+
+    result
+}
+
+/// Smallest size `>= len` of the form `2^a * q^b` the field has a root of unity for, if any.
+pub fn smallest_mixed_size<F: FftField>(len: usize) -> Option<usize> {
+    let q = F::SMALL_SUBGROUP_BASE? as usize;
+    let q_adicity = F::SMALL_SUBGROUP_BASE_ADICITY?;
+    let mut best: Option<usize> = None;
+    let mut q_part = 1usize;
+
+    for _ in 0..=q_adicity {
+        let mut size = q_part;
+
+        while size < len {
+            size = size.checked_mul(2)?;
+        }
+
+        if size.trailing_zeros() <= F::TWO_ADICITY && best.is_none_or(|b| size < b) {
+            best = Some(size);
+        }
+
+        q_part = match q_part.checked_mul(q) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    best
+}
+
+fn smallest_radix<F: FftField>(n: usize) -> usize {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+
+    match F::SMALL_SUBGROUP_BASE {
+        Some(q) if n.is_multiple_of(q as usize) => q as usize,
+        _ => panic!("Size {n} is not of the form 2^a * q^b"),
+    }
+}
@@ -0,0 +1,119 @@
+use ark_ff::FftField;
+
+/// Reorders `values` so that entry `i` moves to the index whose bits are those of `i` reversed.
+pub fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+
+    if n <= 2 {
+        return;
+    }
+
+    let log_n = n.ilog2();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// Returns `[1, w, w^2, ..., w^(n/2 - 1)]`, the only twiddles a size `n` transform needs.
+pub fn twiddle_table<F: FftField>(root: F, n: usize) -> Vec<F> {
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut current = F::one();
+
+    for _ in 0..n / 2 {
+        twiddles.push(current);
+        current *= root;
+    }
+
+    twiddles
+}
+
+/// Cooley–Tukey decimation in time. Takes its input in bit-reversed order and leaves the
+/// transform in natural order. `twiddles` must come from `twiddle_table` for the same size.
+pub fn cooley_tukey_in_place<F: FftField>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    let mut half = 1;
+
+    while half < n {
+        let stride = n / (2 * half);
+
+        for chunk in values.chunks_mut(2 * half) {
+            let (left, right) = chunk.split_at_mut(half);
+
+            for (k, (a, b)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
+                let t = *b * twiddles[k * stride];
+                *b = *a - t;
+                *a += t;
+            }
+        }
+
+        half *= 2;
+    }
+}
+
+/// Gentleman–Sande decimation in frequency. Takes its input in natural order and leaves the
+/// transform in bit-reversed order.
+pub fn gentleman_sande_in_place<F: FftField>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    let mut half = n / 2;
+
+    while half >= 1 {
+        let stride = n / (2 * half);
+
+        for chunk in values.chunks_mut(2 * half) {
+            let (left, right) = chunk.split_at_mut(half);
+
+            for (k, (a, b)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
+                let t = *a - *b;
+                *a += *b;
+                *b = t * twiddles[k * stride];
+            }
+        }
+
+        half /= 2;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        bit_reverse_permutation, cooley_tukey_in_place, gentleman_sande_in_place, twiddle_table,
+    };
+    use crate::fft::dft;
+    use ark_bn254::Fr;
+    use ark_ff::FftField;
+
+    #[test]
+    fn it_bit_reverses() {
+        let mut values: Vec<usize> = (0..8).collect();
+
+        bit_reverse_permutation(&mut values);
+
+        assert_eq!(values, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn both_butterflies_match_the_recursive_dft() {
+        let n = 64;
+        let root = Fr::get_root_of_unity(n as u64).unwrap();
+        let twiddles = twiddle_table(root, n);
+        let values: Vec<Fr> = (0..n).map(|i| Fr::from((i * 7 + 3) as u64)).collect();
+
+        let expected = dft(&values, root);
+
+        let mut dit = values.clone();
+        bit_reverse_permutation(&mut dit);
+        cooley_tukey_in_place(&mut dit, &twiddles);
+
+        let mut dif = values.clone();
+        gentleman_sande_in_place(&mut dif, &twiddles);
+        bit_reverse_permutation(&mut dif);
+
+        assert_eq!(dit, expected);
+        assert_eq!(dif, expected);
+    }
+}
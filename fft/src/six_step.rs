@@ -0,0 +1,146 @@
+use ark_ff::FftField;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::ntt::{bit_reverse_permutation, gentleman_sande_in_place, twiddle_table};
+
+/// Bailey's six-step NTT for a power-of-two `values.len() = n1 * n2`, with `root` a primitive
+/// `n`-th root of unity. Viewing the input as an `n1 x n2` matrix, the transform becomes `n2`
+/// transforms of size `n1`, a twiddle multiplication and `n1` transforms of size `n2`. Each
+/// transform is run over a contiguous row that fits in cache, and rows are independent, so they
+/// are processed in parallel when the `parallel` feature is enabled.
+pub fn six_step_ntt<F: FftField>(values: &mut [F], root: F) {
+    let n = values.len();
+    assert!(
+        n.is_power_of_two(),
+        "Six-step NTT needs a power-of-two size"
+    );
+
+    if n <= 2 {
+        gentleman_sande_in_place(values, &twiddle_table(root, n));
+        return;
+    }
+
+    let n1 = 1 << (n.ilog2() / 2);
+    let n2 = n / n1;
+    let mut scratch = vec![F::zero(); n];
+
+    // 1. Columns j2 of the n1 x n2 input become contiguous rows.
+    transpose(values, &mut scratch, n1, n2);
+
+    // 2. n2 row transforms of size n1.
+    row_ntts(&mut scratch, n1, root.pow([n2 as u64]));
+
+    // 3. Entry (j2, k1) picks up the twiddle root^(j2 * k1).
+    for_each_row(&mut scratch, n1, |j2, row| {
+        let step = root.pow([j2 as u64]);
+        let mut twiddle = F::one();
+
+        for value in row.iter_mut() {
+            *value *= twiddle;
+            twiddle *= step;
+        }
+    });
+
+    // 4. Back to n1 rows of length n2.
+    transpose(&scratch, values, n2, n1);
+
+    // 5. n1 row transforms of size n2.
+    row_ntts(values, n2, root.pow([n1 as u64]));
+
+    // 6. Entry (k1, k2) holds X[k1 + n1 * k2].
+    transpose(values, &mut scratch, n1, n2);
+    values.copy_from_slice(&scratch);
+}
+
+/// Side of the square tiles the transpose works in. A tile of source rows and the matching tile
+/// of destination rows both stay in L1, so neither side is walked with a cache-missing stride.
+const TRANSPOSE_TILE: usize = 16;
+
+/// Writes the transpose of the `rows x cols` row-major matrix `src` into `dst`, one tile at a
+/// time. Each band of `TRANSPOSE_TILE` destination rows is independent of the others.
+fn transpose<F: FftField>(src: &[F], dst: &mut [F], rows: usize, cols: usize) {
+    for_each_row(dst, rows * TRANSPOSE_TILE, |band, dst_band| {
+        let col_start = band * TRANSPOSE_TILE;
+        let col_end = (col_start + TRANSPOSE_TILE).min(cols);
+
+        for row_start in (0..rows).step_by(TRANSPOSE_TILE) {
+            let row_end = (row_start + TRANSPOSE_TILE).min(rows);
+
+            for col in col_start..col_end {
+                let dst_row = &mut dst_band[(col - col_start) * rows..][..rows];
+
+                for row in row_start..row_end {
+                    dst_row[row] = src[row * cols + col];
+                }
+            }
+        }
+    });
+}
+
+fn row_ntts<F: FftField>(values: &mut [F], row_len: usize, root: F) {
+    let twiddles = twiddle_table(root, row_len);
+
+    for_each_row(values, row_len, |_, row| {
+        gentleman_sande_in_place(row, &twiddles);
+        bit_reverse_permutation(row);
+    });
+}
+
+#[cfg(feature = "parallel")]
+fn for_each_row<F: FftField>(
+    values: &mut [F],
+    row_len: usize,
+    f: impl Fn(usize, &mut [F]) + Sync + Send,
+) {
+    values
+        .par_chunks_mut(row_len)
+        .enumerate()
+        .for_each(|(i, row)| f(i, row));
+}
+
+#[cfg(not(feature = "parallel"))]
+fn for_each_row<F: FftField>(values: &mut [F], row_len: usize, f: impl Fn(usize, &mut [F])) {
+    values
+        .chunks_mut(row_len)
+        .enumerate()
+        .for_each(|(i, row)| f(i, row));
+}
+
+#[cfg(test)]
+mod test {
+    use super::{six_step_ntt, transpose};
+    use crate::fft::dft;
+    use ark_bn254::Fr;
+    use ark_ff::FftField;
+
+    #[test]
+    fn it_matches_the_recursive_dft() {
+        for log_n in 0..11 {
+            let n = 1usize << log_n;
+            let root = Fr::get_root_of_unity(n as u64).unwrap();
+            let coeffs: Vec<Fr> = (0..n).map(|i| Fr::from(3 * i as u64 + 1)).collect();
+
+            let mut values = coeffs.clone();
+            six_step_ntt(&mut values, root);
+
+            assert_eq!(values, dft(&coeffs, root));
+        }
+    }
+
+    #[test]
+    fn it_transposes_across_tile_boundaries() {
+        for (rows, cols) in [(1, 1), (3, 5), (16, 16), (64, 32), (20, 37)] {
+            let src: Vec<Fr> = (0..rows * cols).map(|i| Fr::from(i as u64)).collect();
+            let mut dst = vec![Fr::from(0); rows * cols];
+
+            transpose(&src, &mut dst, rows, cols);
+
+            for row in 0..rows {
+                for col in 0..cols {
+                    assert_eq!(dst[col * rows + row], src[row * cols + col]);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use ark_ff::{BigInteger, PrimeField};
+use ark_ff::{BigInteger, Field, PrimeField};
 use sha3::{Digest, Keccak256};
 use std::marker::PhantomData;
 
@@ -27,6 +27,16 @@ impl<F: PrimeField> Transcript<F> {
 
         F::from_le_bytes_mod_order(&random_challenge)
     }
+
+    /// Draws a challenge in an extension `E` of the transcript field, one base
+    /// challenge per coordinate. For `E = F` this is `get_random_challenge`.
+    pub fn get_random_extension_challenge<E: Field<BasePrimeField = F>>(&mut self) -> E {
+        let coordinates: Vec<F> = (0..E::extension_degree())
+            .map(|_| self.get_random_challenge())
+            .collect();
+
+        E::from_base_prime_field_elems(coordinates).unwrap()
+    }
 }
 
 pub fn fq_vec_to_bytes<F: PrimeField>(values: &[F]) -> Vec<u8> {
@@ -36,10 +46,21 @@ pub fn fq_vec_to_bytes<F: PrimeField>(values: &[F]) -> Vec<u8> {
         .collect()
 }
 
+/// Serializes elements of any field, extension or not, through their
+/// base-field coordinates. Agrees with `fq_vec_to_bytes` on prime fields.
+pub fn field_vec_to_bytes<E: Field>(values: &[E]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|x| x.to_base_prime_field_elements())
+        .flat_map(|x| x.into_bigint().to_bytes_le())
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
-    use super::Transcript;
-    use ark_bn254::Fq;
+    use super::{field_vec_to_bytes, fq_vec_to_bytes, Transcript};
+    use ark_bn254::{Fq, Fq2};
+    use ark_ff::Field;
 
     #[test]
     fn it_hashes() {
@@ -50,4 +71,33 @@ mod test {
 
         dbg!(random_challenge);
     }
+
+    #[test]
+    fn it_draws_extension_challenges() {
+        let mut base: Transcript<Fq> = Transcript::new();
+        let mut extension: Transcript<Fq> = Transcript::new();
+        base.append("zero knowledge".as_bytes());
+        extension.append("zero knowledge".as_bytes());
+
+        let same: Fq = extension.get_random_extension_challenge();
+        assert_eq!(same, base.get_random_challenge());
+
+        let challenge: Fq2 = extension.get_random_extension_challenge();
+        assert_eq!(challenge.c0, base.get_random_challenge());
+        assert_eq!(challenge.c1, base.get_random_challenge());
+    }
+
+    #[test]
+    fn it_serializes_extension_elements() {
+        let values = [Fq::from(3), Fq::from(7)];
+        assert_eq!(field_vec_to_bytes(&values), fq_vec_to_bytes(&values));
+
+        let lifted = Fq2::from_base_prime_field(Fq::from(3));
+        let bytes = [
+            fq_vec_to_bytes(&[Fq::from(3)]),
+            fq_vec_to_bytes(&[Fq::from(0)]),
+        ]
+        .concat();
+        assert_eq!(field_vec_to_bytes(&[lifted]), bytes);
+    }
 }
@@ -1,5 +1,8 @@
 use ark_ff::PrimeField;
-use multilinear_polynomial::multilinear_polynomial_evaluation::{MultilinearPoly, Operation};
+use multilinear_polynomial::{
+    multilinear_polynomial_evaluation::{MultilinearPoly, Operation},
+    multilinear_polynomial_sparse::SparseMultilinearPoly,
+};
 
 #[derive(Debug, Clone)]
 pub struct Gate<F: PrimeField> {
@@ -37,18 +40,23 @@ impl<F: PrimeField> Layer<F> {
     }
 
     pub fn get_add_mul_i(&self, op: Operation) -> MultilinearPoly<F> {
-        let n_bits = self.get_bits_for_gates();
-        let layer_size = 1 << n_bits;
-        let mut poly_eval = vec![F::zero(); layer_size];
-
-        let gate_values = self.gate_to_bits();
-        for (gate_value, gate) in gate_values.into_iter().zip(&self.gates) {
-            if gate.op == op {
-                poly_eval[gate_value] = F::one();
-            }
-        }
+        self.get_add_mul_i_sparse(op).to_dense()
+    }
+
+    /// The wiring predicate with one entry per gate of type `op`, instead of the
+    /// `2^(3 log gates)` dense table.
+    pub fn get_add_mul_i_sparse(&self, op: Operation) -> SparseMultilinearPoly<F> {
+        let n_bits = self.get_bits_for_gates() as usize;
+
+        let entries = self
+            .gate_to_bits()
+            .into_iter()
+            .zip(&self.gates)
+            .filter(|(_, gate)| gate.op == op)
+            .map(|(gate_value, _)| (gate_value, F::one()))
+            .collect();
 
-        MultilinearPoly::new(poly_eval)
+        SparseMultilinearPoly::new(n_bits, entries)
     }
 
     fn get_bits_for_gates(&self) -> u32 {
@@ -254,4 +262,27 @@ mod test {
         assert_eq!(expected_mul_1_poly, mul_1_poly.evaluation);
         assert_eq!(expected_mul_2_poly, mul_2_poly.evaluation);
     }
+
+    #[test]
+    fn it_keeps_wiring_predicates_linear_in_the_layer_size() {
+        let gates = (0..8)
+            .map(|i| {
+                let op = if i % 3 == 0 {
+                    Operation::Add
+                } else {
+                    Operation::Mul
+                };
+                Gate::new(Fq::from(i), Fq::from(i + 1), op)
+            })
+            .collect();
+        let layer = Layer::new(gates);
+
+        let add_i = layer.get_add_mul_i_sparse(Operation::Add);
+        let mul_i = layer.get_add_mul_i_sparse(Operation::Mul);
+
+        assert_eq!(add_i.num_of_vars, 11);
+        assert_eq!(add_i.entries.len() + mul_i.entries.len(), 8);
+        assert_eq!(add_i.to_dense(), layer.get_add_mul_i(Operation::Add));
+        assert_eq!(mul_i.to_dense(), layer.get_add_mul_i(Operation::Mul));
+    }
 }
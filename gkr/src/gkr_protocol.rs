@@ -247,11 +247,13 @@ pub fn get_fbc_poly<F: PrimeField>(
     w_c: &[F],
 ) -> SumPoly<F> {
     let add_i = layer
-        .get_add_mul_i(Operation::Add)
-        .partial_evaluate(0, &random_challenge);
+        .get_add_mul_i_sparse(Operation::Add)
+        .partial_evaluate(0, &random_challenge)
+        .to_dense();
     let mul_i = layer
-        .get_add_mul_i(Operation::Mul)
-        .partial_evaluate(0, &random_challenge);
+        .get_add_mul_i_sparse(Operation::Mul)
+        .partial_evaluate(0, &random_challenge)
+        .to_dense();
 
     let summed_w_poly = MultilinearPoly::tensor_add_mul_polynomials(w_b, w_c, Operation::Add);
     let multiplied_w_poly = MultilinearPoly::tensor_add_mul_polynomials(w_b, w_c, Operation::Mul);
@@ -271,14 +273,13 @@ fn get_folded_fbc_poly<F: PrimeField>(
     alpha: F,
     beta: F,
 ) -> SumPoly<F> {
-    let add_i = layer.get_add_mul_i(Operation::Add);
-    let mul_i = layer.get_add_mul_i(Operation::Mul);
+    let add_i = layer.get_add_mul_i_sparse(Operation::Add);
+    let mul_i = layer.get_add_mul_i_sparse(Operation::Mul);
 
-    let summed_add_i = add_i.multi_partial_evaluate(r_b).scale(alpha)
-        + add_i.multi_partial_evaluate(r_c).scale(beta);
-
-    let summed_mul_i = mul_i.multi_partial_evaluate(r_b).scale(alpha)
-        + mul_i.multi_partial_evaluate(r_c).scale(beta);
+    // alpha * f(r_b, b, c) + beta * f(r_c, b, c) in one pass, through a combined eq table.
+    let eq_weights = combine_eq_evals(r_b, r_c, alpha, beta);
+    let summed_add_i = add_i.partial_evaluate_with_eq(&eq_weights).to_dense();
+    let summed_mul_i = mul_i.partial_evaluate_with_eq(&eq_weights).to_dense();
 
     let summed_w_poly = MultilinearPoly::tensor_add_mul_polynomials(w_b, w_c, Operation::Add);
     let multiplied_w_poly = MultilinearPoly::tensor_add_mul_polynomials(w_b, w_c, Operation::Mul);
@@ -304,11 +305,11 @@ fn get_verifier_claim<F: PrimeField>(
     all_random_challenges.extend_from_slice(sumcheck_random_challenges);
 
     let a_r = layer
-        .get_add_mul_i(Operation::Add)
-        .evaluate(all_random_challenges.clone());
+        .get_add_mul_i_sparse(Operation::Add)
+        .evaluate(&all_random_challenges);
     let m_r = layer
-        .get_add_mul_i(Operation::Mul)
-        .evaluate(all_random_challenges);
+        .get_add_mul_i_sparse(Operation::Mul)
+        .evaluate(&all_random_challenges);
 
     (a_r * (o_1 + o_2)) + (m_r * (o_1 * o_2))
 }
@@ -325,21 +326,26 @@ fn get_folded_verifier_claim<F: PrimeField>(
     let (prev_r_b, prev_r_c) =
         previous_random_challenge.split_at(previous_random_challenge.len() / 2);
 
-    let add_i = layer.get_add_mul_i(Operation::Add);
-    let mul_i = layer.get_add_mul_i(Operation::Mul);
-
-    let summed_add_i = add_i.multi_partial_evaluate(prev_r_b).scale(alpha)
-        + add_i.multi_partial_evaluate(prev_r_c).scale(beta);
+    let point_b = [prev_r_b, current_random_challenge].concat();
+    let point_c = [prev_r_c, current_random_challenge].concat();
 
-    let summed_mul_i = mul_i.multi_partial_evaluate(prev_r_b).scale(alpha)
-        + mul_i.multi_partial_evaluate(prev_r_c).scale(beta);
+    let add_i = layer.get_add_mul_i_sparse(Operation::Add);
+    let mul_i = layer.get_add_mul_i_sparse(Operation::Mul);
 
-    let a_r = summed_add_i.evaluate(current_random_challenge.to_vec());
-    let m_r = summed_mul_i.evaluate(current_random_challenge.to_vec());
+    let a_r = alpha * add_i.evaluate(&point_b) + beta * add_i.evaluate(&point_c);
+    let m_r = alpha * mul_i.evaluate(&point_b) + beta * mul_i.evaluate(&point_c);
 
     (a_r * (o_1 + o_2)) + (m_r * (o_1 * o_2))
 }
 
+fn combine_eq_evals<F: PrimeField>(r_b: &[F], r_c: &[F], alpha: F, beta: F) -> Vec<F> {
+    MultilinearPoly::eq_evals(r_b)
+        .into_iter()
+        .zip(MultilinearPoly::eq_evals(r_c))
+        .map(|(eq_b, eq_c)| alpha * eq_b + beta * eq_c)
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::{get_fbc_poly, prove, verify, GkrProof};
@@ -3,26 +3,38 @@ use fiat_shamir::fiat_shamir_transcript::fq_vec_to_bytes;
 use sha3::{Digest, Keccak256};
 use std::error::Error;
 
+/// Prefixed to every leaf preimage, so a leaf can never be passed off as an inner node.
+const LEAF_PREFIX: u8 = 0x00;
+/// Prefixed to every inner node preimage.
+const NODE_PREFIX: u8 = 0x01;
+
 #[derive(PartialEq, Copy, Clone, Debug)]
-enum LeafSide {
+pub enum LeafSide {
     Left,
     Right,
 }
 
-#[derive(Clone)]
-struct ProofData<F: PrimeField> {
-    data_hash: F,
-    data_side: LeafSide,
+#[derive(Clone, Debug)]
+pub struct ProofData<F: PrimeField> {
+    pub data_hash: F,
+    pub data_side: LeafSide,
 }
 
 #[derive(Clone)]
-struct MerkleProof<F: PrimeField> {
+pub struct MerkleProof<F: PrimeField> {
     data: F,
     proof: Vec<ProofData<F>>,
 }
 
+#[derive(Clone, Debug)]
+pub struct MerkleRowProof<F: PrimeField> {
+    pub leaf_id: usize,
+    pub row: Vec<F>,
+    pub proof: Vec<ProofData<F>>,
+}
+
 #[derive(Debug)]
-struct MerkleTree<F: PrimeField> {
+pub struct MerkleTree<F: PrimeField> {
     leaves: Vec<F>,
     tree: Vec<Vec<F>>,
     depth: usize,
@@ -32,23 +44,8 @@ impl<F: PrimeField> MerkleTree<F> {
     pub fn new(depth: usize) -> Self {
         let num_leaves = 1 << depth;
         let leaves = vec![F::zero(); num_leaves];
-        let mut tree = Vec::with_capacity(depth);
-        let mut current_level = leaves.clone();
-
-        for _ in 0..depth {
-            let next_level = current_level
-                .chunks(2)
-                .map(|pair| Self::hash_pair(pair[0], pair[1]))
-                .collect::<Vec<_>>();
-            tree.push(next_level.clone());
-            current_level = next_level;
-        }
 
-        Self {
-            leaves,
-            tree,
-            depth,
-        }
+        Self::build_tree(leaves, depth)
     }
 
     pub fn new_with_inputs(depth: usize, inputs: Vec<F>) -> Result<Self, Box<dyn Error>> {
@@ -62,6 +59,24 @@ impl<F: PrimeField> MerkleTree<F> {
             leaves[i] = Self::compute_hash(*input);
         }
 
+        Ok(Self::build_tree(leaves, depth))
+    }
+
+    pub fn new_with_rows(depth: usize, rows: &[Vec<F>]) -> Result<Self, Box<dyn Error>> {
+        let num_leaves = 1 << depth;
+        if rows.len() > num_leaves {
+            return Err("Too many rows for tree depth".into());
+        }
+
+        let mut leaves = vec![F::zero(); num_leaves];
+        for (i, row) in rows.iter().enumerate() {
+            leaves[i] = Self::compute_row_hash(row);
+        }
+
+        Ok(Self::build_tree(leaves, depth))
+    }
+
+    fn build_tree(leaves: Vec<F>, depth: usize) -> Self {
         let mut tree = Vec::with_capacity(depth);
         let mut current_level = leaves.clone();
 
@@ -74,11 +89,11 @@ impl<F: PrimeField> MerkleTree<F> {
             current_level = next_level;
         }
 
-        Ok(Self {
+        Self {
             leaves,
             tree,
             depth,
-        })
+        }
     }
 
     pub fn update_leaf(
@@ -150,6 +165,33 @@ impl<F: PrimeField> MerkleTree<F> {
             return Err("Data does not match the leaf hash".into());
         }
 
+        Ok(MerkleProof {
+            data: data_to_prove,
+            proof: self.get_path(leaf_id),
+        })
+    }
+
+    pub fn create_row_proof(
+        &self,
+        row: &[F],
+        leaf_id: usize,
+    ) -> Result<MerkleRowProof<F>, Box<dyn Error>> {
+        if leaf_id >= 1 << self.depth {
+            return Err("Invalid leaf ID".into());
+        }
+
+        if self.leaves[leaf_id] != Self::compute_row_hash(row) {
+            return Err("Row does not match the leaf hash".into());
+        }
+
+        Ok(MerkleRowProof {
+            leaf_id,
+            row: row.to_vec(),
+            proof: self.get_path(leaf_id),
+        })
+    }
+
+    fn get_path(&self, leaf_id: usize) -> Vec<ProofData<F>> {
         let mut proof = Vec::with_capacity(self.depth);
         let mut index = leaf_id;
 
@@ -162,31 +204,68 @@ impl<F: PrimeField> MerkleTree<F> {
                 self.tree[level - 1][sibling_index]
             };
 
-            let data_side = if index % 2 == 0 {
-                LeafSide::Right
-            } else {
-                LeafSide::Left
-            };
-
             proof.push(ProofData {
                 data_hash: sibling_hash,
-                data_side,
+                data_side: Self::sibling_side(index),
             });
 
             index /= 2;
         }
 
-        Ok(MerkleProof {
-            data: data_to_prove,
-            proof,
-        })
+        proof
     }
 
     pub fn verify(&self, proof: MerkleProof<F>) -> bool {
+        if proof.proof.len() != self.depth {
+            return false;
+        }
+
         let root_hash = self.get_root_hash();
-        let mut current_hash = Self::compute_hash(proof.data);
+        let leaf_hash = Self::compute_hash(proof.data);
+
+        root_hash == Self::compute_root_from_path(leaf_hash, &proof.proof)
+    }
+
+    /// Checks a row opening against a root and the depth of the committed tree only, so a
+    /// verifier never needs the tree itself. The path must have exactly `depth` steps whose
+    /// sides match the bits of `leaf_id`, otherwise a valid row could be passed off as the
+    /// opening of a different position, or a subtree as a leaf.
+    pub fn verify_row(root_hash: F, depth: usize, proof: &MerkleRowProof<F>) -> bool {
+        if proof.proof.len() != depth {
+            return false;
+        }
 
-        for proof_data in proof.proof {
+        let mut index = proof.leaf_id;
+
+        for proof_data in proof.proof.iter() {
+            if proof_data.data_side != Self::sibling_side(index) {
+                return false;
+            }
+
+            index /= 2;
+        }
+
+        if index != 0 {
+            return false;
+        }
+
+        let leaf_hash = Self::compute_row_hash(&proof.row);
+
+        root_hash == Self::compute_root_from_path(leaf_hash, &proof.proof)
+    }
+
+    fn sibling_side(index: usize) -> LeafSide {
+        if index & 1 == 0 {
+            LeafSide::Right
+        } else {
+            LeafSide::Left
+        }
+    }
+
+    fn compute_root_from_path(leaf_hash: F, path: &[ProofData<F>]) -> F {
+        let mut current_hash = leaf_hash;
+
+        for proof_data in path {
             let (left, right) = match proof_data.data_side {
                 LeafSide::Left => (proof_data.data_hash, current_hash),
                 LeafSide::Right => (current_hash, proof_data.data_hash),
@@ -195,18 +274,28 @@ impl<F: PrimeField> MerkleTree<F> {
             current_hash = Self::hash_pair(left, right);
         }
 
-        root_hash == current_hash
+        current_hash
     }
 
     fn compute_hash(data: F) -> F {
         let mut hasher = Keccak256::new();
+        hasher.update([LEAF_PREFIX]);
         hasher.update(fq_vec_to_bytes(&[data]));
 
         F::from_le_bytes_mod_order(&hasher.finalize_reset())
     }
 
+    fn compute_row_hash(row: &[F]) -> F {
+        let mut hasher = Keccak256::new();
+        hasher.update([LEAF_PREFIX]);
+        hasher.update(fq_vec_to_bytes(row));
+
+        F::from_le_bytes_mod_order(&hasher.finalize_reset())
+    }
+
     fn hash_pair(left: F, right: F) -> F {
         let mut hasher = Keccak256::new();
+        hasher.update([NODE_PREFIX]);
         hasher.update(fq_vec_to_bytes(&[left]));
         hasher.update(fq_vec_to_bytes(&[right]));
 
@@ -365,4 +454,121 @@ mod test {
         let too_many_inputs = vec![Fq::from(1); 5];
         assert!(MerkleTree::<Fq>::new_with_inputs(depth, too_many_inputs).is_err());
     }
+
+    #[test]
+    fn test_row_proof_and_verify() {
+        let depth = 2;
+        let rows = vec![
+            vec![Fq::from(1), Fq::from(2), Fq::from(3)],
+            vec![Fq::from(4), Fq::from(5), Fq::from(6)],
+            vec![Fq::from(7), Fq::from(8), Fq::from(9)],
+            vec![Fq::from(10), Fq::from(11), Fq::from(12)],
+        ];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+        let root_hash = merkle_tree.get_root_hash();
+
+        for (i, row) in rows.iter().enumerate() {
+            let proof = merkle_tree.create_row_proof(row, i).unwrap();
+
+            assert_eq!(proof.row, *row);
+            assert!(MerkleTree::verify_row(root_hash, depth, &proof));
+        }
+    }
+
+    #[test]
+    fn test_row_proof_rejects_tampered_row() {
+        let depth = 2;
+        let rows = vec![
+            vec![Fq::from(1), Fq::from(2)],
+            vec![Fq::from(3), Fq::from(4)],
+            vec![Fq::from(5), Fq::from(6)],
+        ];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+        let root_hash = merkle_tree.get_root_hash();
+
+        let mut proof = merkle_tree.create_row_proof(&rows[1], 1).unwrap();
+        proof.row[0] = Fq::from(30);
+
+        assert!(!MerkleTree::verify_row(root_hash, depth, &proof));
+        assert!(merkle_tree.create_row_proof(&proof.row, 1).is_err());
+    }
+
+    #[test]
+    fn test_row_proof_rejects_wrong_position() {
+        let depth = 2;
+        let rows = vec![
+            vec![Fq::from(1), Fq::from(2)],
+            vec![Fq::from(3), Fq::from(4)],
+            vec![Fq::from(5), Fq::from(6)],
+            vec![Fq::from(7), Fq::from(8)],
+        ];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+        let root_hash = merkle_tree.get_root_hash();
+
+        let mut proof = merkle_tree.create_row_proof(&rows[2], 2).unwrap();
+        proof.leaf_id = 3;
+
+        assert!(!MerkleTree::verify_row(root_hash, depth, &proof));
+    }
+
+    #[test]
+    fn test_row_proof_rejects_inner_node_as_row() {
+        let depth = 2;
+        let rows = vec![
+            vec![Fq::from(1), Fq::from(2)],
+            vec![Fq::from(3), Fq::from(4)],
+            vec![Fq::from(5), Fq::from(6)],
+            vec![Fq::from(7), Fq::from(8)],
+        ];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+        let root_hash = merkle_tree.get_root_hash();
+        let honest = merkle_tree.create_row_proof(&rows[0], 0).unwrap();
+
+        // Opens the two leaf hashes under the first inner node as if they were a row.
+        let forged = MerkleRowProof {
+            leaf_id: 0,
+            row: vec![merkle_tree.leaves[0], merkle_tree.leaves[1]],
+            proof: honest.proof[1..].to_vec(),
+        };
+
+        assert!(!MerkleTree::verify_row(root_hash, depth, &forged));
+        assert!(!MerkleTree::verify_row(root_hash, depth - 1, &forged));
+        assert!(MerkleTree::verify_row(root_hash, depth, &honest));
+    }
+
+    #[test]
+    fn test_row_proof_rejects_wrong_depth() {
+        let depth = 2;
+        let rows = vec![vec![Fq::from(1)], vec![Fq::from(2)]];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+        let root_hash = merkle_tree.get_root_hash();
+
+        let mut proof = merkle_tree.create_row_proof(&rows[1], 1).unwrap();
+        assert!(!MerkleTree::verify_row(root_hash, depth + 1, &proof));
+
+        proof.proof.pop();
+        assert!(!MerkleTree::verify_row(root_hash, depth, &proof));
+    }
+
+    #[test]
+    fn test_new_with_rows() {
+        let depth = 1;
+        let rows = vec![vec![Fq::from(1), Fq::from(2)]];
+
+        let merkle_tree = MerkleTree::<Fq>::new_with_rows(depth, &rows).unwrap();
+
+        assert_eq!(
+            merkle_tree.leaves[0],
+            MerkleTree::<Fq>::compute_row_hash(&rows[0])
+        );
+        assert_eq!(merkle_tree.leaves[1], Fq::from(0));
+
+        let too_many_rows = vec![vec![Fq::from(1)]; 3];
+        assert!(MerkleTree::<Fq>::new_with_rows(depth, &too_many_rows).is_err());
+    }
 }
@@ -1,25 +1,27 @@
 use ark_bn254::Fq;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ark_ff::Field;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use small_fields::{babybear::BabyBear, goldilocks::Goldilocks, mersenne31::Mersenne31};
 
-pub fn criterion_benchmark(c: &mut Criterion) {
+fn bench_evaluate<F: Field>(c: &mut Criterion, name: &str) {
     // Set the number of variables; our polynomial will have 2^(num_vars) evaluations.
     let num_vars = 10;
     let num_evals = 1 << num_vars;
 
     // Build a vector of evaluations for the polynomial.
-    // Here we simply use Fq::from(i) for each index.
-    let evaluations: Vec<Fq> = (0..num_evals).map(|i| Fq::from(i as u64)).collect();
+    // Here we simply use F::from(i) for each index.
+    let evaluations: Vec<F> = (0..num_evals).map(|i| F::from(i as u64)).collect();
 
     // Construct the multilinear polynomial.
     let poly = MultilinearPoly::new(evaluations);
 
     // Define a fixed assignment for each variable.
     // In this case, we evaluate the polynomial at (2, 2, ..., 2).
-    let values: Vec<Fq> = vec![Fq::from(2); num_vars];
+    let values: Vec<F> = vec![F::from(2u64); num_vars];
 
     // Benchmark the full evaluation of the polynomial.
-    c.bench_function("MultilinearPoly evaluate", |b| {
+    c.bench_function(name, |b| {
         b.iter(|| {
             // Use black_box to prevent compiler optimizations from removing our computation.
             let result = poly.evaluate(black_box(values.clone()));
@@ -28,5 +30,67 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_benchmark);
+pub fn criterion_benchmark(c: &mut Criterion) {
+    bench_evaluate::<Fq>(c, "MultilinearPoly evaluate");
+    bench_evaluate::<Goldilocks>(c, "MultilinearPoly evaluate (Goldilocks)");
+    bench_evaluate::<BabyBear>(c, "MultilinearPoly evaluate (BabyBear)");
+    bench_evaluate::<Mersenne31>(c, "MultilinearPoly evaluate (Mersenne31)");
+}
+
+/// Compares the pair-list `partial_evaluate` with the in-place folds, and the previous
+/// clone-and-fold evaluation with the streaming `evaluate`, on a 2^18-entry polynomial.
+pub fn in_place_benchmark(c: &mut Criterion) {
+    let num_vars = 18;
+    let evaluations: Vec<Fq> = (0..1u64 << num_vars).map(Fq::from).collect();
+    let poly = MultilinearPoly::new(evaluations);
+    let value = Fq::from(7);
+    let values: Vec<Fq> = (0..num_vars as u64).map(|i| Fq::from(i + 2)).collect();
+
+    let mut group = c.benchmark_group("MultilinearPoly 2^18");
+    group.sample_size(10);
+
+    group.bench_function("partial_evaluate first", |b| {
+        b.iter(|| black_box(poly.partial_evaluate(0, black_box(&value))))
+    });
+    group.bench_function("fix_first_variable_in_place", |b| {
+        b.iter_batched(
+            || poly.clone(),
+            |mut poly| {
+                poly.fix_first_variable_in_place(black_box(&value));
+                poly
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("partial_evaluate last", |b| {
+        b.iter(|| black_box(poly.partial_evaluate(num_vars - 1, black_box(&value))))
+    });
+    group.bench_function("fix_last_variable_in_place", |b| {
+        b.iter_batched(
+            || poly.clone(),
+            |mut poly| {
+                poly.fix_last_variable_in_place(black_box(&value));
+                poly
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("evaluate by repeated partial_evaluate", |b| {
+        b.iter(|| {
+            let mut folded = poly.clone();
+            for value in values.iter() {
+                folded = folded.partial_evaluate(0, value);
+            }
+            black_box(folded.evaluation[0])
+        })
+    });
+    group.bench_function("streaming evaluate", |b| {
+        b.iter(|| black_box(poly.evaluate(black_box(values.clone()))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark, in_place_benchmark);
 criterion_main!(benches);
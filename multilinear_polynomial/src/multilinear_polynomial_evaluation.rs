@@ -1,4 +1,4 @@
-use ark_ff::PrimeField;
+use ark_ff::{Field, PrimeField};
 use std::ops::{Add, Mul, Sub};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,7 +8,7 @@ pub enum Operation {
 }
 
 impl Operation {
-    pub fn apply<F: PrimeField>(self, a: F, b: F) -> F {
+    pub fn apply<F: Field>(self, a: F, b: F) -> F {
         match self {
             Operation::Add => a + b,
             Operation::Mul => a * b,
@@ -17,12 +17,12 @@ impl Operation {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct MultilinearPoly<F: PrimeField> {
+pub struct MultilinearPoly<F: Field> {
     pub evaluation: Vec<F>,
     pub num_of_vars: usize,
 }
 
-impl<F: PrimeField> MultilinearPoly<F> {
+impl<F: Field> MultilinearPoly<F> {
     pub fn new(evaluations: Vec<F>) -> Self {
         let num_of_vars: usize = evaluations.len().ilog2() as usize;
 
@@ -62,6 +62,43 @@ impl<F: PrimeField> MultilinearPoly<F> {
         Self::new(result)
     }
 
+    /// Fixes the first (most significant) variable to `value`, folding the upper half of the
+    /// evaluations into the lower half and truncating the buffer.
+    pub fn fix_first_variable_in_place(&mut self, value: &F) {
+        if self.num_of_vars == 0 {
+            panic!("No variable left to fix");
+        }
+
+        let half = self.evaluation.len() / 2;
+        let (low, high) = self.evaluation.split_at_mut(half);
+
+        for (a, b) in low.iter_mut().zip(high.iter()) {
+            *a += *value * (*b - *a);
+        }
+
+        self.evaluation.truncate(half);
+        self.num_of_vars -= 1;
+    }
+
+    /// Fixes the last (least significant) variable to `value`, folding adjacent pairs. Entry `i`
+    /// only reads entries `2i` and `2i + 1`, so a forward pass can overwrite the buffer.
+    pub fn fix_last_variable_in_place(&mut self, value: &F) {
+        if self.num_of_vars == 0 {
+            panic!("No variable left to fix");
+        }
+
+        let half = self.evaluation.len() / 2;
+
+        for i in 0..half {
+            let a = self.evaluation[2 * i];
+            let b = self.evaluation[2 * i + 1];
+            self.evaluation[i] = a + *value * (b - a);
+        }
+
+        self.evaluation.truncate(half);
+        self.num_of_vars -= 1;
+    }
+
     pub fn multi_partial_evaluate(&self, values: &[F]) -> Self {
         if values.len() > self.num_of_vars {
             panic!("Invalid number of values");
@@ -70,24 +107,103 @@ impl<F: PrimeField> MultilinearPoly<F> {
         let mut poly = self.clone();
 
         for value in values {
-            poly = poly.partial_evaluate(0, value);
+            poly.fix_first_variable_in_place(value);
         }
 
         poly
     }
 
+    /// Streams over the evaluations without copying them. Entries are folded like a binary
+    /// counter: every trailing one bit of an index completes a subcube, which is combined with
+    /// its left sibling, so only one pending value per variable is kept.
     pub fn evaluate(&self, values: Vec<F>) -> F {
         if values.len() != self.num_of_vars {
             panic!("Invalid number of values");
         }
 
-        let mut result = self.clone();
+        let mut pending: Vec<F> = Vec::with_capacity(self.num_of_vars + 1);
 
-        for value in values.iter() {
-            result = result.partial_evaluate(0, value);
+        for (index, eval) in self.evaluation.iter().enumerate() {
+            let mut acc = *eval;
+            let mut bits = index;
+            let mut var = self.num_of_vars;
+
+            while bits & 1 == 1 {
+                var -= 1;
+                let left = pending.pop().unwrap();
+                acc = left + values[var] * (acc - left);
+                bits >>= 1;
+            }
+
+            pending.push(acc);
+        }
+
+        pending[0]
+    }
+
+    /// Evaluations of `eq(r, x)` over the hypercube, with `r[0]` as the most significant index
+    /// bit like `partial_evaluate(0, ..)`. Each variable doubles the table in place, so the
+    /// whole table costs one multiplication per entry.
+    pub fn eq_evals(r: &[F]) -> Vec<F> {
+        let mut evals = vec![F::zero(); 1 << r.len()];
+        evals[0] = F::one();
+
+        for (i, r_i) in r.iter().enumerate() {
+            for k in (0..1 << i).rev() {
+                let high = evals[k] * r_i;
+                evals[2 * k + 1] = high;
+                evals[2 * k] = evals[k] - high;
+            }
+        }
+
+        evals
+    }
+
+    /// `eq(x, y) = prod_i (x_i * y_i + (1 - x_i) * (1 - y_i))`, in O(n).
+    pub fn eq_eval(x: &[F], y: &[F]) -> F {
+        if x.len() != y.len() {
+            panic!("Points must have the same number of variables");
         }
 
-        result.evaluation[0]
+        x.iter()
+            .zip(y.iter())
+            .map(|(x_i, y_i)| {
+                let product = *x_i * y_i;
+                F::one() - x_i - y_i + product + product
+            })
+            .product()
+    }
+
+    /// Evaluates at the point whose `eq_evals` table is given, as an inner product.
+    pub fn evaluate_with_eq(&self, eq_evals: &[F]) -> F {
+        if eq_evals.len() != self.evaluation.len() {
+            panic!("Invalid eq table size");
+        }
+
+        self.evaluation
+            .iter()
+            .zip(eq_evals.iter())
+            .map(|(eval, eq)| *eval * eq)
+            .sum()
+    }
+
+    /// Fixes the leading variables with an eq table over them, which may also be a linear
+    /// combination of eq tables to fix several points at once.
+    pub fn partial_evaluate_with_eq(&self, eq_evals: &[F]) -> Self {
+        if eq_evals.len() > self.evaluation.len() || !eq_evals.len().is_power_of_two() {
+            panic!("Invalid eq table size");
+        }
+
+        let chunk_size = self.evaluation.len() / eq_evals.len();
+        let mut result = vec![F::zero(); chunk_size];
+
+        for (chunk, eq) in self.evaluation.chunks(chunk_size).zip(eq_evals.iter()) {
+            for (acc, eval) in result.iter_mut().zip(chunk.iter()) {
+                *acc += *eval * eq;
+            }
+        }
+
+        Self::new(result)
     }
 
     pub fn scale(&self, value: F) -> Self {
@@ -110,7 +226,41 @@ impl<F: PrimeField> MultilinearPoly<F> {
     }
 }
 
-impl<F: PrimeField> Add for MultilinearPoly<F> {
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Partially evaluates a base-field polynomial at a point of an extension
+    /// `E`. Only `b - a` is multiplied by `value`, as a base-by-extension
+    /// product, so folding the witness costs no full extension multiplications.
+    pub fn partial_evaluate_ext<E: Field<BasePrimeField = F>>(
+        &self,
+        bit: usize,
+        value: &E,
+    ) -> MultilinearPoly<E> {
+        let result = Self::pair_points(bit, self.num_of_vars)
+            .into_iter()
+            .map(|(a, b)| {
+                let a = self.evaluation[a];
+                let b = self.evaluation[b];
+
+                E::from_base_prime_field(a) + value.mul_by_base_prime_field(&(b - a))
+            })
+            .collect();
+
+        MultilinearPoly::new(result)
+    }
+
+    pub fn evaluate_ext<E: Field<BasePrimeField = F>>(&self, values: &[E]) -> E {
+        if values.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        match values.split_first() {
+            None => E::from_base_prime_field(self.evaluation[0]),
+            Some((first, rest)) => self.partial_evaluate_ext(0, first).evaluate(rest.to_vec()),
+        }
+    }
+}
+
+impl<F: Field> Add for MultilinearPoly<F> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -125,7 +275,7 @@ impl<F: PrimeField> Add for MultilinearPoly<F> {
     }
 }
 
-impl<F: PrimeField> Mul for MultilinearPoly<F> {
+impl<F: Field> Mul for MultilinearPoly<F> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
@@ -140,7 +290,7 @@ impl<F: PrimeField> Mul for MultilinearPoly<F> {
     }
 }
 
-impl<F: PrimeField> Sub for MultilinearPoly<F> {
+impl<F: Field> Sub for MultilinearPoly<F> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -167,33 +317,166 @@ fn insert_bit(value: usize, bit: usize) -> usize {
 mod test {
     use super::*;
     use field_tracker::{print_summary, Ft};
+    use small_fields::{
+        babybear::{BabyBear, BabyBear4},
+        goldilocks::{Goldilocks, Goldilocks2},
+        mersenne31::{Mersenne31, Mersenne31Quartic},
+    };
 
     type Fq = Ft!(ark_bn254::Fq);
 
-    #[test]
-    fn it_partially_evaluates_any_multilinear() {
-        let evaluations = vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(10)];
+    fn partially_evaluates<F: Field>() {
+        let evaluations = vec![F::from(0u64), F::from(0u64), F::from(3u64), F::from(10u64)];
         let polynomial = MultilinearPoly::new(evaluations);
 
-        let value_a = Fq::from(5);
+        let value_a = F::from(5u64);
         let bit_a = 0;
 
         let result = polynomial.partial_evaluate(bit_a, &value_a);
 
-        assert_eq!(result.evaluation, vec![Fq::from(15), Fq::from(50)]);
+        assert_eq!(result.evaluation, vec![F::from(15u64), F::from(50u64)]);
+    }
+
+    fn fully_evaluates<F: Field>() {
+        let evaluations = vec![F::from(0u64), F::from(0u64), F::from(3u64), F::from(10u64)];
+        let polynomial = MultilinearPoly::new(evaluations);
+
+        let values = vec![F::from(5u64), F::from(1u64)];
+
+        let result = polynomial.evaluate(values);
+
+        assert_eq!(result, F::from(50u64));
+    }
+
+    fn evaluates_over_extension<F: PrimeField, E: Field<BasePrimeField = F>>() {
+        let evaluations: Vec<F> = [0u64, 0, 3, 10, 4, 1, 7, 2].map(F::from).to_vec();
+        let polynomial = MultilinearPoly::new(evaluations.clone());
+        let lifted = MultilinearPoly::new(
+            evaluations
+                .into_iter()
+                .map(E::from_base_prime_field)
+                .collect(),
+        );
+
+        let point: Vec<E> = (0..3u64)
+            .map(|i| {
+                let coordinates = (0..E::extension_degree()).map(|j| F::from(5 * i + j + 2));
+                E::from_base_prime_field_elems(coordinates).unwrap()
+            })
+            .collect();
+
+        assert_eq!(
+            polynomial.partial_evaluate_ext(1, &point[0]),
+            lifted.partial_evaluate(1, &point[0])
+        );
+        assert_eq!(polynomial.evaluate_ext(&point), lifted.evaluate(point));
+    }
+
+    fn builds_eq_tables<F: Field>() {
+        let r = [F::from(5u64), F::from(2u64), F::from(3u64)];
+        let eq = MultilinearPoly::eq_evals(&r);
+
+        // Same table as the KZG Lagrange basis for taus (5, 2, 3).
+        let expected: Vec<F> = [-8i64, 12, 16, -24, 10, -15, -20, 30].map(F::from).to_vec();
+        assert_eq!(eq, expected);
+
+        for (index, value) in eq.iter().enumerate() {
+            let vertex: Vec<F> = (0..3)
+                .map(|bit| F::from(((index >> (2 - bit)) & 1) as u64))
+                .collect();
+            assert_eq!(MultilinearPoly::eq_eval(&r, &vertex), *value);
+        }
+
+        let evaluations: Vec<F> = (0..8u64).map(|i| F::from(i * i + 1)).collect();
+        let polynomial = MultilinearPoly::new(evaluations);
+        assert_eq!(
+            polynomial.evaluate_with_eq(&eq),
+            polynomial.evaluate(r.to_vec())
+        );
+        assert_eq!(
+            polynomial.partial_evaluate_with_eq(&MultilinearPoly::eq_evals(&r[..2])),
+            polynomial.multi_partial_evaluate(&r[..2])
+        );
+    }
+
+    #[test]
+    fn it_fixes_variables_in_place() {
+        let evaluations: Vec<Fq> = (0..16u64).map(|i| Fq::from(i * i + 1)).collect();
+        let polynomial = MultilinearPoly::new(evaluations);
+        let value = Fq::from(7);
+
+        let mut first = polynomial.clone();
+        first.fix_first_variable_in_place(&value);
+        assert_eq!(first, polynomial.partial_evaluate(0, &value));
+
+        let mut last = polynomial.clone();
+        last.fix_last_variable_in_place(&value);
+        assert_eq!(last, polynomial.partial_evaluate(3, &value));
+    }
+
+    #[test]
+    fn it_evaluates_by_streaming() {
+        let evaluations: Vec<Fq> = (0..32u64).map(|i| Fq::from(3 * i + 2)).collect();
+        let polynomial = MultilinearPoly::new(evaluations);
+        let values: Vec<Fq> = (0..5u64).map(|i| Fq::from(i + 11)).collect();
+
+        let mut folded = polynomial.clone();
+        for value in values.iter() {
+            folded = folded.partial_evaluate(0, value);
+        }
+
+        assert_eq!(polynomial.evaluate(values), folded.evaluation[0]);
+        assert_eq!(
+            MultilinearPoly::new(vec![Fq::from(9)]).evaluate(vec![]),
+            Fq::from(9)
+        );
+    }
+
+    #[test]
+    fn it_partially_evaluates_any_multilinear() {
+        partially_evaluates::<Fq>();
 
         print_summary!();
     }
 
     #[test]
     fn it_fully_evaluates_any_multilinear() {
-        let evaluations = vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(10)];
-        let polynomial = MultilinearPoly::new(evaluations);
+        fully_evaluates::<Fq>();
+    }
 
-        let values = vec![Fq::from(5), Fq::from(1)];
+    #[test]
+    fn it_builds_eq_tables() {
+        builds_eq_tables::<Fq>();
+        builds_eq_tables::<Goldilocks>();
+    }
 
-        let result = polynomial.evaluate(values);
+    #[test]
+    fn it_evaluates_on_boolean_points_with_eq() {
+        let zero = Fq::from(0);
+        let one = Fq::from(1);
 
-        assert_eq!(result, Fq::from(50));
+        assert_eq!(MultilinearPoly::eq_eval(&[one, zero], &[one, zero]), one);
+        assert_eq!(MultilinearPoly::eq_eval(&[one, zero], &[one, one]), zero);
+        assert_eq!(MultilinearPoly::<Fq>::eq_evals(&[]), vec![one]);
+    }
+
+    #[test]
+    fn it_evaluates_over_an_extension_field() {
+        evaluates_over_extension::<ark_bn254::Fq, ark_bn254::Fq2>();
+    }
+
+    #[test]
+    fn it_evaluates_over_small_fields() {
+        partially_evaluates::<Goldilocks>();
+        partially_evaluates::<BabyBear>();
+        partially_evaluates::<Mersenne31>();
+
+        fully_evaluates::<Goldilocks>();
+        fully_evaluates::<BabyBear>();
+        fully_evaluates::<Mersenne31>();
+
+        evaluates_over_extension::<Goldilocks, Goldilocks2>();
+        evaluates_over_extension::<BabyBear, BabyBear4>();
+        evaluates_over_extension::<Mersenne31, Mersenne31Quartic>();
     }
 }
@@ -0,0 +1,224 @@
+use ark_ff::Field;
+
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+
+/// A multilinear polynomial stored as its non-zero `(index, value)` evaluations on the
+/// hypercube, sorted by index. Indices follow `MultilinearPoly`: variable 0 is the most
+/// significant bit. Suits wiring predicates, which are zero almost everywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMultilinearPoly<F: Field> {
+    pub entries: Vec<(usize, F)>,
+    pub num_of_vars: usize,
+}
+
+impl<F: Field> SparseMultilinearPoly<F> {
+    /// Sorts the entries, merges repeated indices and drops zero values.
+    pub fn new(num_of_vars: usize, mut entries: Vec<(usize, F)>) -> Self {
+        if entries.iter().any(|(index, _)| *index >> num_of_vars != 0) {
+            panic!("Index outside the hypercube");
+        }
+
+        entries.sort_by_key(|(index, _)| *index);
+
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(entries.len());
+
+        for (index, value) in entries {
+            match merged.last_mut() {
+                Some((last_index, last_value)) if *last_index == index => *last_value += value,
+                _ => merged.push((index, value)),
+            }
+        }
+
+        merged.retain(|(_, value)| !value.is_zero());
+
+        SparseMultilinearPoly {
+            entries: merged,
+            num_of_vars,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// `sum_x f(x) * eq(values, x)` over the non-zero entries only, in O(nnz * n).
+    pub fn evaluate(&self, values: &[F]) -> F {
+        if values.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        let one_minus: Vec<F> = values.iter().map(|value| F::one() - value).collect();
+
+        self.entries
+            .iter()
+            .map(|(index, value)| {
+                (0..self.num_of_vars).fold(*value, |acc, var| {
+                    if (index >> (self.num_of_vars - 1 - var)) & 1 == 1 {
+                        acc * values[var]
+                    } else {
+                        acc * one_minus[var]
+                    }
+                })
+            })
+            .sum()
+    }
+
+    /// Fixes variable `bit` to `value`. Every entry moves to the index with that bit removed,
+    /// weighted by `value` or `1 - value`, so the result has at most as many entries.
+    pub fn partial_evaluate(&self, bit: usize, value: &F) -> Self {
+        if bit >= self.num_of_vars {
+            panic!("Invalid variable");
+        }
+
+        let shift = self.num_of_vars - bit - 1;
+        let one_minus = F::one() - value;
+
+        let entries = self
+            .entries
+            .iter()
+            .map(|(index, eval)| {
+                let high = (index >> (shift + 1)) << shift;
+                let low = index & ((1 << shift) - 1);
+                let weight = if (index >> shift) & 1 == 1 {
+                    *value
+                } else {
+                    one_minus
+                };
+
+                (high | low, *eval * weight)
+            })
+            .collect();
+
+        Self::new(self.num_of_vars - 1, entries)
+    }
+
+    /// Fixes the leading variables with a (combination of) eq table(s) over them, as
+    /// `MultilinearPoly::partial_evaluate_with_eq` does, touching only the non-zero entries.
+    pub fn partial_evaluate_with_eq(&self, eq_evals: &[F]) -> Self {
+        if eq_evals.len() > 1 << self.num_of_vars || !eq_evals.len().is_power_of_two() {
+            panic!("Invalid eq table size");
+        }
+
+        let remaining_vars = self.num_of_vars - eq_evals.len().ilog2() as usize;
+        let mask = (1 << remaining_vars) - 1;
+
+        let entries = self
+            .entries
+            .iter()
+            .map(|(index, eval)| (index & mask, *eval * eq_evals[index >> remaining_vars]))
+            .collect();
+
+        Self::new(remaining_vars, entries)
+    }
+
+    pub fn to_dense(&self) -> MultilinearPoly<F> {
+        let mut evaluations = vec![F::zero(); 1 << self.num_of_vars];
+
+        for (index, value) in &self.entries {
+            evaluations[*index] = *value;
+        }
+
+        MultilinearPoly::new(evaluations)
+    }
+
+    pub fn from_dense(poly: &MultilinearPoly<F>) -> Self {
+        SparseMultilinearPoly::new(
+            poly.num_of_vars,
+            poly.evaluation.iter().copied().enumerate().collect(),
+        )
+    }
+}
+
+impl<F: Field> From<SparseMultilinearPoly<F>> for MultilinearPoly<F> {
+    fn from(poly: SparseMultilinearPoly<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: Field> From<MultilinearPoly<F>> for SparseMultilinearPoly<F> {
+    fn from(poly: MultilinearPoly<F>) -> Self {
+        SparseMultilinearPoly::from_dense(&poly)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SparseMultilinearPoly;
+    use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+    use ark_bn254::Fq;
+
+    fn sample_sparse() -> SparseMultilinearPoly<Fq> {
+        SparseMultilinearPoly::new(
+            4,
+            vec![(13, Fq::from(3)), (2, Fq::from(5)), (7, Fq::from(-2))],
+        )
+    }
+
+    fn sample_point() -> Vec<Fq> {
+        vec![Fq::from(4), Fq::from(9), Fq::from(-3), Fq::from(11)]
+    }
+
+    #[test]
+    fn it_normalizes_entries() {
+        let poly = SparseMultilinearPoly::new(
+            3,
+            vec![
+                (5, Fq::from(2)),
+                (1, Fq::from(1)),
+                (5, Fq::from(3)),
+                (3, Fq::from(4)),
+                (3, Fq::from(-4)),
+            ],
+        );
+
+        assert_eq!(poly.entries, vec![(1, Fq::from(1)), (5, Fq::from(5))]);
+        assert!(SparseMultilinearPoly::<Fq>::new(2, vec![]).is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_rejects_indices_outside_the_hypercube() {
+        SparseMultilinearPoly::new(2, vec![(4, Fq::from(1))]);
+    }
+
+    #[test]
+    fn it_converts_to_and_from_dense() {
+        let sparse = sample_sparse();
+        let dense = sparse.to_dense();
+
+        assert_eq!(dense.evaluation.len(), 16);
+        assert_eq!(dense.evaluation[13], Fq::from(3));
+        assert_eq!(SparseMultilinearPoly::from(dense), sparse);
+    }
+
+    #[test]
+    fn it_evaluates_like_the_dense_polynomial() {
+        let sparse = sample_sparse();
+        let dense = sparse.to_dense();
+
+        assert_eq!(
+            sparse.evaluate(&sample_point()),
+            dense.evaluate(sample_point())
+        );
+    }
+
+    #[test]
+    fn it_partially_evaluates_like_the_dense_polynomial() {
+        let sparse = sample_sparse();
+        let dense = sparse.to_dense();
+        let value = Fq::from(6);
+
+        for bit in 0..4 {
+            assert_eq!(
+                sparse.partial_evaluate(bit, &value).to_dense(),
+                dense.partial_evaluate(bit, &value)
+            );
+        }
+
+        let eq_evals = MultilinearPoly::eq_evals(&sample_point()[..2]);
+        assert_eq!(
+            sparse.partial_evaluate_with_eq(&eq_evals).to_dense(),
+            dense.partial_evaluate_with_eq(&eq_evals)
+        );
+    }
+}
@@ -168,44 +168,12 @@ fn blow_up_poly<F: PrimeField>(poly: &[F], bigger_poly_len: usize) -> Vec<F> {
     MultilinearPoly::tensor_add_mul_polynomials(&blow_up_poly, poly, Operation::Mul).evaluation
 }
 
-fn generate_bhc(bits: usize) -> Vec<Vec<u8>> {
-    let total = 1usize << bits;
-
-    (0..total)
-        .map(|i| {
-            (0..bits)
-                .map(|j| ((i >> (bits - 1 - j)) & 1) as u8)
-                .collect()
-        })
-        .collect()
-}
-
 fn get_lagrange_basis<F: PrimeField>(num_of_vars: usize, unenc_taus: &[F], g_1: G1) -> Vec<G1> {
-    if num_of_vars < 1 {
+    if num_of_vars < 1 || unenc_taus.len() != num_of_vars {
         panic!("Invalid num of vars for lagrange basis");
     }
 
-    let mut result = Vec::with_capacity(1 << num_of_vars);
-    let bhc = generate_bhc(num_of_vars);
-
-    for layer in bhc {
-        let mut layer_eval = F::one();
-
-        for (i, bit) in layer.iter().enumerate() {
-            let bit_scalar;
-            if *bit == 0 {
-                bit_scalar = F::one() - unenc_taus[i];
-            } else {
-                bit_scalar = unenc_taus[i];
-            }
-
-            layer_eval *= bit_scalar;
-        }
-
-        result.push(layer_eval);
-    }
-
-    result
+    MultilinearPoly::eq_evals(unenc_taus)
         .iter()
         .map(|x| g_1.mul_bigint(x.into_bigint()))
         .collect()
@@ -0,0 +1,229 @@
+use crate::shamir_secret_sharing::{check_indices, SecretSharingError, Share};
+use ark_ff::PrimeField;
+use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
+
+/// Recovers the secret even when some shares are corrupted. With `n` shares of a polynomial of
+/// degree `threshold - 1`, up to `(n - threshold) / 2` bad shares are corrected, and their indices
+/// are returned alongside the secret.
+pub fn robust_reconstruct<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+) -> Result<(F, Vec<usize>), SecretSharingError> {
+    let poly = decode(shares, threshold)?;
+
+    let cheaters = shares
+        .iter()
+        .filter(|share| poly.evaluate(F::from(share.index as u64)) != share.value)
+        .map(|share| share.index)
+        .collect();
+
+    Ok((poly.evaluate(F::zero()), cheaters))
+}
+
+/// Berlekamp–Welch: find an error locator `E` (monic, degree `e`) and `Q = P * E` such that
+/// `Q(x_i) = y_i * E(x_i)` for every share, then `P = Q / E`.
+pub fn decode<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+) -> Result<UnivariatePoly<F>, SecretSharingError> {
+    check_indices(shares)?;
+
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+
+    if shares.len() < threshold {
+        return Err(SecretSharingError::NotEnoughShares {
+            required: threshold,
+            received: shares.len(),
+        });
+    }
+
+    let max_errors = (shares.len() - threshold) / 2;
+    let q_len = max_errors + threshold;
+    let num_of_unknowns = q_len + max_errors;
+
+    // Unknowns are the coefficients of Q followed by the non-leading coefficients of E.
+    let rows: Vec<Vec<F>> = shares
+        .iter()
+        .map(|share| {
+            let x = F::from(share.index as u64);
+            let y = share.value;
+
+            let mut row = Vec::with_capacity(num_of_unknowns + 1);
+            let mut x_pow = F::one();
+
+            for _ in 0..q_len {
+                row.push(x_pow);
+                x_pow *= x;
+            }
+
+            x_pow = F::one();
+            for _ in 0..max_errors {
+                row.push(-y * x_pow);
+                x_pow *= x;
+            }
+
+            row.push(y * x_pow);
+
+            row
+        })
+        .collect();
+
+    let solution =
+        solve_linear_system(rows, num_of_unknowns).ok_or(SecretSharingError::TooManyErrors)?;
+
+    let q = solution[..q_len].to_vec();
+    let mut e = solution[q_len..].to_vec();
+    e.push(F::one());
+
+    let (poly, remainder) = UnivariatePoly::new(q).div_rem(&UnivariatePoly::new(e));
+
+    if remainder.coefficient.iter().any(|coeff| !coeff.is_zero()) {
+        return Err(SecretSharingError::TooManyErrors);
+    }
+
+    let agreements = shares
+        .iter()
+        .filter(|share| poly.evaluate(F::from(share.index as u64)) == share.value)
+        .count();
+
+    if agreements < shares.len() - max_errors {
+        return Err(SecretSharingError::TooManyErrors);
+    }
+
+    Ok(poly)
+}
+
+/// Gaussian elimination on an augmented matrix. Free variables are set to zero; returns `None`
+/// when the system is inconsistent.
+fn solve_linear_system<F: PrimeField>(
+    mut rows: Vec<Vec<F>>,
+    num_of_unknowns: usize,
+) -> Option<Vec<F>> {
+    let mut pivot_columns = Vec::new();
+    let mut pivot_row = 0;
+
+    for col in 0..num_of_unknowns {
+        let Some(found) = (pivot_row..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+
+        rows.swap(pivot_row, found);
+
+        let inv = rows[pivot_row][col].inverse().unwrap();
+        for value in rows[pivot_row].iter_mut() {
+            *value *= inv;
+        }
+
+        for r in 0..rows.len() {
+            if r != pivot_row && !rows[r][col].is_zero() {
+                let factor = rows[r][col];
+                let pivot = rows[pivot_row].clone();
+
+                for (value, pivot_value) in rows[r].iter_mut().zip(pivot.iter()) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+
+        pivot_columns.push(col);
+        pivot_row += 1;
+    }
+
+    if rows[pivot_row..]
+        .iter()
+        .any(|row| !row[num_of_unknowns].is_zero())
+    {
+        return None;
+    }
+
+    let mut solution = vec![F::zero(); num_of_unknowns];
+    for (r, col) in pivot_columns.into_iter().enumerate() {
+        solution[col] = rows[r][num_of_unknowns];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::robust_reconstruct;
+    use crate::shamir_secret_sharing::{split, SecretSharingError};
+    use ark_bn254::Fq;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn it_reconstructs_with_no_errors() {
+        let secret = Fq::from(77);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let shares = split(secret, 3, 7, &mut rng);
+
+        assert_eq!(robust_reconstruct(&shares, 3), Ok((secret, vec![])));
+    }
+
+    #[test]
+    fn it_corrects_up_to_max_errors_and_names_cheaters() {
+        let secret = Fq::from(77);
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let mut shares = split(secret, 3, 7, &mut rng);
+
+        shares[1].value += Fq::from(5);
+        shares[5].value = Fq::from(0);
+
+        let (recovered, cheaters) = robust_reconstruct(&shares, 3).unwrap();
+
+        assert_eq!(recovered, secret);
+        assert_eq!(cheaters, vec![2, 6]);
+    }
+
+    #[test]
+    fn it_corrects_a_single_error() {
+        let secret = Fq::from(12345);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let mut shares = split(secret, 4, 7, &mut rng);
+
+        shares[0].value += Fq::from(1);
+
+        assert_eq!(robust_reconstruct(&shares, 4), Ok((secret, vec![1])));
+    }
+
+    #[test]
+    fn it_fails_with_too_many_errors() {
+        let secret = Fq::from(77);
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let mut shares = split(secret, 3, 5, &mut rng);
+
+        shares[0].value += Fq::from(1);
+        shares[1].value += Fq::from(2);
+
+        assert_eq!(
+            robust_reconstruct(&shares, 3),
+            Err(SecretSharingError::TooManyErrors)
+        );
+    }
+
+    #[test]
+    fn it_rejects_too_few_shares_or_a_zero_threshold() {
+        let secret = Fq::from(77);
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let shares = split(secret, 3, 5, &mut rng);
+
+        assert_eq!(
+            robust_reconstruct(&shares[..2], 3),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 3,
+                received: 2
+            })
+        );
+        assert_eq!(
+            robust_reconstruct(&shares, 0),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+    }
+}
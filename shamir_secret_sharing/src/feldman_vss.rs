@@ -0,0 +1,131 @@
+use crate::shamir_secret_sharing::{create_polynomial, share_points, Share};
+use ark_ec::PrimeGroup;
+use ark_ff::Field;
+use ark_std::rand::Rng;
+
+/// Commitments `g^{a_j}` to every coefficient of the dealer's polynomial, published alongside the shares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeldmanCommitment<G: PrimeGroup> {
+    pub commitments: Vec<G>,
+}
+
+pub fn deal<G: PrimeGroup, R: Rng>(
+    secret: G::ScalarField,
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> (Vec<Share<G::ScalarField>>, FeldmanCommitment<G>) {
+    let poly = create_polynomial(threshold, secret, rng);
+    let shares = share_points(num_of_shares, threshold, &poly);
+
+    let g = G::generator();
+    let commitments = poly.coefficient.iter().map(|coeff| g * coeff).collect();
+
+    (shares, FeldmanCommitment { commitments })
+}
+
+/// Checks `g^{share} == prod_j C_j^{index^j}`, i.e. that the share lies on the committed polynomial.
+/// The dealer must commit to exactly `threshold` coefficients, otherwise the polynomial could have
+/// a higher degree and different subsets of shares would rebuild different secrets.
+pub fn verify_share<G: PrimeGroup>(
+    index: usize,
+    share: G::ScalarField,
+    commitment: &FeldmanCommitment<G>,
+    threshold: usize,
+) -> bool {
+    if index == 0 || commitment.commitments.len() != threshold {
+        return false;
+    }
+
+    G::generator() * share == evaluate_commitments(&commitment.commitments, index)
+}
+
+/// `prod_j C_j^{index^j}`: the committed polynomial evaluated at `index` in the exponent.
+pub(crate) fn evaluate_commitments<G: PrimeGroup>(commitments: &[G], index: usize) -> G {
+    let index = G::ScalarField::from(index as u64);
+    let mut index_power = G::ScalarField::ONE;
+    let mut expected = G::zero();
+
+    for coeff_commitment in commitments {
+        expected += *coeff_commitment * index_power;
+        index_power *= index;
+    }
+
+    expected
+}
+
+#[cfg(test)]
+mod test {
+    use super::{deal, verify_share};
+    use crate::shamir_secret_sharing::{reconstruct, reconstruct_with_threshold};
+    use ark_bls12_381::{Fr, G1Projective as G1};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn it_verifies_honest_shares() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (shares, commitment) = deal::<G1, _>(Fr::from(40), 3, 5, &mut rng);
+
+        assert_eq!(commitment.commitments.len(), 3);
+
+        for share in shares {
+            assert!(verify_share(share.index, share.value, &commitment, 3));
+        }
+    }
+
+    #[test]
+    fn it_detects_a_tampered_share() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (shares, commitment) = deal::<G1, _>(Fr::from(40), 3, 5, &mut rng);
+
+        let share = shares[2];
+
+        assert!(!verify_share(
+            share.index,
+            share.value + Fr::from(1),
+            &commitment,
+            3
+        ));
+        assert!(!verify_share(share.index + 1, share.value, &commitment, 3));
+    }
+
+    #[test]
+    fn it_rejects_a_dealer_committing_to_a_higher_degree() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let (shares, commitment) = deal::<G1, _>(Fr::from(40), 4, 5, &mut rng);
+
+        assert_ne!(
+            reconstruct_with_threshold(&shares[..2], 2),
+            reconstruct_with_threshold(&shares[2..], 2)
+        );
+
+        for share in shares {
+            assert!(verify_share(share.index, share.value, &commitment, 4));
+            assert!(!verify_share(share.index, share.value, &commitment, 2));
+        }
+    }
+
+    #[test]
+    fn it_rejects_the_secret_index() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (_, commitment) = deal::<G1, _>(Fr::from(40), 3, 5, &mut rng);
+
+        assert!(!verify_share(0, Fr::from(40), &commitment, 3));
+    }
+
+    #[test]
+    fn it_recovers_secret_from_verified_shares() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let (shares, _) = deal::<G1, _>(Fr::from(40), 3, 5, &mut rng);
+
+        assert_eq!(reconstruct(&shares[1..4]), Ok(Fr::from(40)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_doesnt_deal_fewer_shares_than_threshold() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let _ = deal::<G1, _>(Fr::from(40), 3, 2, &mut rng);
+    }
+}
@@ -1 +1,7 @@
+pub mod berlekamp_welch;
+pub mod feldman_vss;
+pub mod packed_secret_sharing;
+pub mod pedersen_vss;
+pub mod proactive_refresh;
 pub mod shamir_secret_sharing;
+pub mod share_arithmetic;
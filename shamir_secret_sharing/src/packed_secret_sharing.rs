@@ -0,0 +1,345 @@
+use crate::shamir_secret_sharing::{
+    check_indices, interpolate_at, lagrange_coefficients_over, select_shares, share_points,
+    SecretSharingError, Share,
+};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use fft::fft_domain::FftDomain;
+use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
+
+/// The `j`-th of the `k` secrets sits at `-j`, so the secret points never collide with the share
+/// indices `1..=n` and a single packed secret is ordinary Shamir sharing at zero.
+pub fn secret_point<F: PrimeField>(j: usize) -> F {
+    -F::from(j as u64)
+}
+
+/// Hides all of `secrets` in one polynomial of degree `threshold + k - 2`. Any `threshold - 1`
+/// shares reveal nothing, and any `threshold + k - 1` shares recover every secret.
+pub fn packed_split<F: PrimeField, R: Rng>(
+    secrets: &[F],
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> Vec<Share<F>> {
+    if secrets.is_empty() || threshold == 0 {
+        panic!("Need at least one secret and a threshold of at least 1");
+    }
+
+    let num_of_secrets = secrets.len();
+
+    let mut points: Vec<(F, F)> = secrets
+        .iter()
+        .enumerate()
+        .map(|(j, secret)| (secret_point(j), *secret))
+        .collect();
+
+    points.extend(
+        (num_of_secrets..num_of_secrets + threshold - 1).map(|j| (secret_point(j), F::rand(rng))),
+    );
+
+    let poly = UnivariatePoly::interpolate(points);
+
+    share_points(num_of_shares, threshold + num_of_secrets - 1, &poly)
+}
+
+pub fn packed_reconstruct<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+    num_of_secrets: usize,
+) -> Result<Vec<F>, SecretSharingError> {
+    let selected_shares = select_shares(shares, threshold + num_of_secrets - 1)?;
+
+    Ok((0..num_of_secrets)
+        .map(|j| interpolate_at(selected_shares, secret_point(j)))
+        .collect())
+}
+
+/// FFT variant of `packed_split` for when `threshold + k - 1` and `num_of_shares` are powers of
+/// two. The secrets and the randomness fill the subgroup `H` of size `threshold + k - 1`, secrets
+/// first, and share `i` is the evaluation at `g * w^(i - 1)` on the coset `g * H'` of the subgroup
+/// of size `n`, where `g` is the field's multiplicative generator. One `ifft` and one
+/// `coset_fft` replace the quadratic interpolation and evaluation.
+pub fn packed_split_fft<F: PrimeField, R: Rng>(
+    secrets: &[F],
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    let num_of_slots = fft_slots(threshold, secrets.len())?;
+
+    if num_of_shares < num_of_slots {
+        return Err(SecretSharingError::NotEnoughShares {
+            required: num_of_slots,
+            received: num_of_shares,
+        });
+    }
+
+    let (secret_domain, share_domain) = fft_domains(num_of_slots, num_of_shares)?;
+
+    let mut slots = secrets.to_vec();
+    slots.extend((0..threshold - 1).map(|_| F::rand(rng)));
+
+    let coeffs = secret_domain.ifft(&slots);
+
+    Ok(share_domain
+        .coset_fft(&coeffs, F::GENERATOR)
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| Share::new(i + 1, value))
+        .collect())
+}
+
+/// Inverse of `packed_split_fft`. With every share present it runs a `coset_ifft` followed by an
+/// `fft` over `H`; otherwise it interpolates through the coset points of the first
+/// `threshold + k - 1` shares.
+pub fn packed_reconstruct_fft<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+    num_of_secrets: usize,
+    num_of_shares: usize,
+) -> Result<Vec<F>, SecretSharingError> {
+    let num_of_slots = fft_slots(threshold, num_of_secrets)?;
+    let (secret_domain, share_domain) = fft_domains(num_of_slots, num_of_shares)?;
+
+    check_indices(shares)?;
+
+    if let Some(share) = shares.iter().find(|share| share.index > num_of_shares) {
+        return Err(SecretSharingError::IndexOutOfRange(share.index));
+    }
+
+    if shares.len() == num_of_shares {
+        let mut evals = vec![F::zero(); num_of_shares];
+
+        for share in shares {
+            evals[share.index - 1] = share.value;
+        }
+
+        let coeffs = share_domain.coset_ifft(&evals, F::GENERATOR);
+        let mut slots = secret_domain.fft(&coeffs[..num_of_slots]);
+        slots.truncate(num_of_secrets);
+
+        return Ok(slots);
+    }
+
+    let selected_shares = select_shares(shares, num_of_slots)?;
+
+    let xs: Vec<F> = selected_shares
+        .iter()
+        .map(|share| F::GENERATOR * share_domain.element(share.index - 1))
+        .collect();
+
+    Ok((0..num_of_secrets)
+        .map(|j| {
+            lagrange_coefficients_over(&xs, secret_domain.element(j))
+                .iter()
+                .zip(selected_shares.iter())
+                .map(|(lambda, share)| *lambda * share.value)
+                .sum()
+        })
+        .collect())
+}
+
+fn fft_slots(threshold: usize, num_of_secrets: usize) -> Result<usize, SecretSharingError> {
+    if num_of_secrets == 0 {
+        return Err(SecretSharingError::NoSecrets);
+    }
+
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+
+    Ok(threshold + num_of_secrets - 1)
+}
+
+fn fft_domains<F: PrimeField>(
+    num_of_slots: usize,
+    num_of_shares: usize,
+) -> Result<(FftDomain<F>, FftDomain<F>), SecretSharingError> {
+    let domain = |size: usize| {
+        if !size.is_power_of_two() {
+            return Err(SecretSharingError::NoFftDomain(size));
+        }
+
+        FftDomain::new(size).map_err(|_| SecretSharingError::NoFftDomain(size))
+    };
+
+    Ok((domain(num_of_slots)?, domain(num_of_shares)?))
+}
+
+/// Splits an arbitrary number of secrets in packs of `pack_size`. Entry `p` of the result holds
+/// every party's share of pack `p`; the last pack is padded with zeros.
+pub fn batch_split<F: PrimeField, R: Rng>(
+    secrets: &[F],
+    pack_size: usize,
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> Vec<Vec<Share<F>>> {
+    secrets
+        .chunks(pack_size)
+        .map(|pack| {
+            let mut pack = pack.to_vec();
+            pack.resize(pack_size, F::zero());
+
+            packed_split(&pack, threshold, num_of_shares, rng)
+        })
+        .collect()
+}
+
+pub fn batch_reconstruct<F: PrimeField>(
+    packs: &[Vec<Share<F>>],
+    pack_size: usize,
+    threshold: usize,
+    num_of_secrets: usize,
+) -> Result<Vec<F>, SecretSharingError> {
+    let mut secrets = Vec::with_capacity(packs.len() * pack_size);
+
+    for pack in packs {
+        secrets.extend(packed_reconstruct(pack, threshold, pack_size)?);
+    }
+
+    secrets.truncate(num_of_secrets);
+
+    Ok(secrets)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        batch_reconstruct, batch_split, packed_reconstruct, packed_reconstruct_fft, packed_split,
+        packed_split_fft,
+    };
+    use crate::shamir_secret_sharing::{reconstruct_with_threshold, SecretSharingError};
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::FftField;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use fft::fft_domain::FftDomain;
+    use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
+
+    #[test]
+    fn it_packs_and_recovers_several_secrets() {
+        let secrets = vec![Fq::from(3), Fq::from(1), Fq::from(4), Fq::from(1)];
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let shares = packed_split(&secrets, 3, 10, &mut rng);
+
+        assert_eq!(shares.len(), 10);
+        assert_eq!(packed_reconstruct(&shares, 3, 4), Ok(secrets.clone()));
+        assert_eq!(packed_reconstruct(&shares[4..10], 3, 4), Ok(secrets));
+    }
+
+    #[test]
+    fn it_needs_threshold_plus_k_minus_one_shares() {
+        let secrets = vec![Fq::from(3), Fq::from(1), Fq::from(4)];
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let shares = packed_split(&secrets, 2, 6, &mut rng);
+
+        assert_eq!(
+            packed_reconstruct(&shares[..3], 2, 3),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 4,
+                received: 3
+            })
+        );
+    }
+
+    #[test]
+    fn it_matches_plain_shamir_for_a_single_secret() {
+        let secret = Fq::from(42);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let shares = packed_split(&[secret], 3, 5, &mut rng);
+
+        assert_eq!(reconstruct_with_threshold(&shares, 3), Ok(secret));
+    }
+
+    #[test]
+    fn it_batches_many_secrets() {
+        let secrets: Vec<Fq> = (0..50).map(|i| Fq::from(i * i + 7)).collect();
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let packs = batch_split(&secrets, 8, 3, 12, &mut rng);
+
+        assert_eq!(packs.len(), 7);
+        assert_eq!(batch_reconstruct(&packs, 8, 3, secrets.len()), Ok(secrets));
+    }
+
+    #[test]
+    fn it_matches_the_interpolation_path_with_ffts() {
+        let secrets: Vec<Fr> = (1..=5).map(Fr::from).collect();
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let shares = packed_split_fft(&secrets, 4, 16, &mut rng).unwrap();
+        assert_eq!(shares.len(), 16);
+
+        let secret_domain = FftDomain::<Fr>::new(8).unwrap();
+        let share_domain = FftDomain::<Fr>::new(16).unwrap();
+        let coset_point = |index: usize| Fr::GENERATOR * share_domain.element(index - 1);
+
+        let poly = UnivariatePoly::interpolate(
+            shares[..8]
+                .iter()
+                .map(|share| (coset_point(share.index), share.value))
+                .collect(),
+        );
+
+        for share in &shares {
+            assert_eq!(poly.evaluate(coset_point(share.index)), share.value);
+        }
+
+        for (j, secret) in secrets.iter().enumerate() {
+            assert_eq!(poly.evaluate(secret_domain.element(j)), *secret);
+        }
+
+        assert_eq!(
+            packed_reconstruct_fft(&shares, 4, 5, 16),
+            Ok(secrets.clone())
+        );
+        assert_eq!(
+            packed_reconstruct_fft(&shares[5..13], 4, 5, 16),
+            Ok(secrets)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_fft_parameters() {
+        let secrets = vec![Fr::from(3), Fr::from(1), Fr::from(4)];
+        let mut rng = StdRng::seed_from_u64(6);
+
+        assert_eq!(
+            packed_split_fft(&secrets, 3, 8, &mut rng),
+            Err(SecretSharingError::NoFftDomain(5))
+        );
+        assert_eq!(
+            packed_split_fft(&[Fq::from(1)], 2, 4, &mut rng),
+            Err(SecretSharingError::NoFftDomain(4))
+        );
+
+        assert_eq!(
+            packed_split_fft::<Fr, _>(&[], 2, 8, &mut rng),
+            Err(SecretSharingError::NoSecrets)
+        );
+        assert_eq!(
+            packed_split_fft(&secrets, 0, 8, &mut rng),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+        assert_eq!(
+            packed_split_fft(&secrets, 2, 2, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 4,
+                received: 2
+            })
+        );
+
+        let shares = packed_split_fft(&secrets, 2, 8, &mut rng).unwrap();
+
+        assert_eq!(
+            packed_reconstruct_fft(&shares[..3], 2, 3, 8),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 4,
+                received: 3
+            })
+        );
+    }
+}
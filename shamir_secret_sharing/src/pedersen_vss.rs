@@ -0,0 +1,174 @@
+use crate::{
+    feldman_vss::evaluate_commitments,
+    shamir_secret_sharing::{create_polynomial, share_points, Share},
+};
+use ark_ec::PrimeGroup;
+use ark_ff::{PrimeField, UniformRand};
+use ark_std::rand::Rng;
+
+/// The two generators of a Pedersen commitment. Nobody may know `log_g(h)`, so `h` must come from
+/// outside the dealer, e.g. hashed to the group or fixed by a public setup. A dealer who picked
+/// `h = g^x` could open a commitment to any value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PedersenParams<G: PrimeGroup> {
+    pub g: G,
+    pub h: G,
+}
+
+/// Commitments `g^{a_j} h^{b_j}` to the coefficients of the secret and blinding polynomials.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedersenCommitment<G: PrimeGroup> {
+    pub commitments: Vec<G>,
+}
+
+/// A share of the secret polynomial together with the matching share of the blinding polynomial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PedersenShare<F: PrimeField> {
+    pub share: Share<F>,
+    pub blinding: F,
+}
+
+impl<G: PrimeGroup> PedersenParams<G> {
+    pub fn new(g: G, h: G) -> Self {
+        if g.is_zero() || h.is_zero() || g == h {
+            panic!("Pedersen generators must be distinct and non-zero");
+        }
+
+        Self { g, h }
+    }
+}
+
+pub fn deal<G: PrimeGroup, R: Rng>(
+    params: &PedersenParams<G>,
+    secret: G::ScalarField,
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> (Vec<PedersenShare<G::ScalarField>>, PedersenCommitment<G>) {
+    let poly = create_polynomial(threshold, secret, rng);
+    let blinding_poly = create_polynomial(threshold, G::ScalarField::rand(rng), rng);
+
+    let commitments = poly
+        .coefficient
+        .iter()
+        .zip(blinding_poly.coefficient.iter())
+        .map(|(a, b)| params.g * a + params.h * b)
+        .collect();
+
+    let shares = share_points(num_of_shares, threshold, &poly)
+        .into_iter()
+        .zip(share_points(num_of_shares, threshold, &blinding_poly))
+        .map(|(share, blinding)| PedersenShare {
+            share,
+            blinding: blinding.value,
+        })
+        .collect();
+
+    (shares, PedersenCommitment { commitments })
+}
+
+/// Checks `g^{share} h^{blinding} == prod_j C_j^{index^j}` against exactly `threshold`
+/// coefficient commitments, so the dealer can't hide a higher-degree polynomial.
+pub fn verify_share<G: PrimeGroup>(
+    params: &PedersenParams<G>,
+    share: &PedersenShare<G::ScalarField>,
+    commitment: &PedersenCommitment<G>,
+    threshold: usize,
+) -> bool {
+    if share.share.index == 0 || commitment.commitments.len() != threshold {
+        return false;
+    }
+
+    params.g * share.share.value + params.h * share.blinding
+        == evaluate_commitments(&commitment.commitments, share.share.index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{deal, verify_share, PedersenParams};
+    use crate::shamir_secret_sharing::reconstruct;
+    use ark_bls12_381::{Fr, G1Projective as G1};
+    use ark_ec::PrimeGroup;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    // A fixed multiple of the generator. Its discrete log is public, which is fine for testing
+    // the sharing but would break binding in a real setup.
+    fn test_params() -> PedersenParams<G1> {
+        PedersenParams::new(G1::generator(), G1::generator() * Fr::from(0x5eed))
+    }
+
+    #[test]
+    fn it_verifies_honest_shares() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let params = test_params();
+
+        let (shares, commitment) = deal(&params, Fr::from(40), 3, 5, &mut rng);
+
+        for share in shares.iter() {
+            assert!(verify_share(&params, share, &commitment, 3));
+        }
+    }
+
+    #[test]
+    fn it_detects_a_tampered_share() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let params = test_params();
+
+        let (shares, commitment) = deal(&params, Fr::from(40), 3, 5, &mut rng);
+
+        let mut bad_share = shares[1];
+        bad_share.share.value += Fr::from(1);
+
+        let mut bad_blinding = shares[1];
+        bad_blinding.blinding += Fr::from(1);
+
+        assert!(!verify_share(&params, &bad_share, &commitment, 3));
+        assert!(!verify_share(&params, &bad_blinding, &commitment, 3));
+    }
+
+    #[test]
+    fn it_rejects_a_higher_degree_or_the_secret_index() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let params = test_params();
+
+        let (shares, commitment) = deal(&params, Fr::from(40), 4, 5, &mut rng);
+
+        let mut zero_index = shares[0];
+        zero_index.share.index = 0;
+
+        for share in shares.iter() {
+            assert!(!verify_share(&params, share, &commitment, 2));
+        }
+
+        assert!(!verify_share(&params, &zero_index, &commitment, 4));
+    }
+
+    #[test]
+    fn it_hides_the_secret_behind_the_blinding() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let params = test_params();
+
+        let (_, commitment_1) = deal(&params, Fr::from(40), 3, 5, &mut rng);
+        let (_, commitment_2) = deal(&params, Fr::from(40), 3, 5, &mut rng);
+
+        assert_ne!(commitment_1.commitments[0], commitment_2.commitments[0]);
+    }
+
+    #[test]
+    fn it_recovers_secret_from_verified_shares() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let params = test_params();
+
+        let (shares, _) = deal(&params, Fr::from(40), 3, 5, &mut rng);
+
+        let secret_shares: Vec<_> = shares[2..5].iter().map(|share| share.share).collect();
+
+        assert_eq!(reconstruct(&secret_shares), Ok(Fr::from(40)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_rejects_equal_generators() {
+        PedersenParams::new(G1::generator(), G1::generator());
+    }
+}
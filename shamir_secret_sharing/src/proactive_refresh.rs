@@ -0,0 +1,271 @@
+use crate::shamir_secret_sharing::{
+    check_index_list, check_indices, create_polynomial, lagrange_coefficients_at, select_shares,
+    share_points_at, split, SecretSharingError, Share,
+};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+
+/// One party's contribution to a refresh: a fresh sharing of zero with the same threshold.
+pub fn zero_sharing<F: PrimeField, R: Rng>(
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> Vec<Share<F>> {
+    split(F::zero(), threshold, num_of_shares, rng)
+}
+
+/// Adds the sub-shares a party received from every zero sharing onto its current share.
+pub fn apply_refresh<F: PrimeField>(share: &Share<F>, sub_shares: &[Share<F>]) -> Share<F> {
+    if sub_shares
+        .iter()
+        .any(|sub_share| sub_share.index != share.index)
+    {
+        panic!("sub share is addressed to a different party");
+    }
+
+    let delta: F = sub_shares.iter().map(|sub_share| sub_share.value).sum();
+
+    Share::new(share.index, share.value + delta)
+}
+
+/// Simulates a refresh round among the holders of `shares`: every party deals a sharing of zero,
+/// and every party adds what it received. The secret is unchanged but old and new shares no longer
+/// combine, so shares leaked before the refresh become useless.
+pub fn refresh_shares<F: PrimeField, R: Rng>(
+    shares: &[Share<F>],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    check_indices(shares)?;
+
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+
+    if shares.len() < threshold {
+        return Err(SecretSharingError::NotEnoughShares {
+            required: threshold,
+            received: shares.len(),
+        });
+    }
+
+    let max_index = shares.iter().map(|share| share.index).max().unwrap();
+
+    let dealings: Vec<Vec<Share<F>>> = shares
+        .iter()
+        .map(|_| zero_sharing(threshold, max_index, rng))
+        .collect();
+
+    Ok(shares
+        .iter()
+        .map(|share| {
+            let received: Vec<Share<F>> = dealings
+                .iter()
+                .map(|dealing| dealing[share.index - 1])
+                .collect();
+
+            apply_refresh(share, &received)
+        })
+        .collect())
+}
+
+/// Moves a secret from an old `(old_threshold, n)` committee to a new `(new_threshold,
+/// new_num_of_shares)` committee without ever reconstructing it. Each of `old_threshold` old
+/// parties reshares its own share; each new party combines the sub-shares with the Lagrange
+/// coefficients of the old parties at zero.
+pub fn reshare<F: PrimeField, R: Rng>(
+    old_shares: &[Share<F>],
+    old_threshold: usize,
+    new_threshold: usize,
+    new_num_of_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    let new_indices: Vec<usize> = (1..=new_num_of_shares).collect();
+
+    reshare_to(old_shares, old_threshold, new_threshold, &new_indices, rng)
+}
+
+/// Same as `reshare`, but the new committee is the parties at `new_indices`, which need not be
+/// numbered from 1.
+pub fn reshare_to<F: PrimeField, R: Rng>(
+    old_shares: &[Share<F>],
+    old_threshold: usize,
+    new_threshold: usize,
+    new_indices: &[usize],
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    let dealers = select_shares(old_shares, old_threshold)?;
+
+    if new_threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+
+    if new_indices.len() < new_threshold {
+        return Err(SecretSharingError::NotEnoughShares {
+            required: new_threshold,
+            received: new_indices.len(),
+        });
+    }
+
+    check_index_list(new_indices)?;
+
+    let indices: Vec<usize> = dealers.iter().map(|share| share.index).collect();
+    let lambdas = lagrange_coefficients_at(&indices, F::zero());
+
+    let dealings: Vec<Vec<Share<F>>> = dealers
+        .iter()
+        .map(|share| {
+            let poly = create_polynomial(new_threshold, share.value, rng);
+
+            share_points_at(new_indices, &poly)
+        })
+        .collect();
+
+    Ok(new_indices
+        .iter()
+        .enumerate()
+        .map(|(j, index)| {
+            let value = dealings
+                .iter()
+                .zip(lambdas.iter())
+                .map(|(dealing, lambda)| *lambda * dealing[j].value)
+                .sum();
+
+            Share::new(*index, value)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{refresh_shares, reshare};
+    use crate::shamir_secret_sharing::{
+        reconstruct_with_threshold, split, SecretSharingError, Share,
+    };
+    use ark_bn254::Fq;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn it_refreshes_without_changing_the_secret() {
+        let secret = Fq::from(2024);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let shares = split(secret, 3, 5, &mut rng);
+        let refreshed = refresh_shares(&shares, 3, &mut rng).unwrap();
+
+        assert_eq!(refreshed.len(), shares.len());
+
+        for (old, new) in shares.iter().zip(refreshed.iter()) {
+            assert_eq!(old.index, new.index);
+            assert_ne!(old.value, new.value);
+        }
+
+        assert_eq!(reconstruct_with_threshold(&refreshed, 3), Ok(secret));
+        assert_eq!(reconstruct_with_threshold(&refreshed[2..], 3), Ok(secret));
+    }
+
+    #[test]
+    fn it_makes_leaked_old_shares_useless() {
+        let secret = Fq::from(2024);
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let shares = split(secret, 3, 5, &mut rng);
+        let refreshed = refresh_shares(&shares, 3, &mut rng).unwrap();
+
+        let mixed = vec![shares[0], shares[1], refreshed[2]];
+
+        assert_ne!(reconstruct_with_threshold(&mixed, 3), Ok(secret));
+    }
+
+    #[test]
+    fn it_reshares_to_a_new_committee() {
+        let secret = Fq::from(31337);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let old_shares = split(secret, 3, 5, &mut rng);
+
+        let new_shares = reshare(&old_shares[1..4], 3, 4, 7, &mut rng).unwrap();
+
+        assert_eq!(new_shares.len(), 7);
+        assert_eq!(reconstruct_with_threshold(&new_shares, 4), Ok(secret));
+        assert_eq!(reconstruct_with_threshold(&new_shares[3..], 4), Ok(secret));
+        assert_ne!(reconstruct_with_threshold(&new_shares, 3), Ok(secret));
+    }
+
+    #[test]
+    fn it_reshares_to_a_smaller_threshold() {
+        let secret = Fq::from(31337);
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let old_shares = split(secret, 4, 6, &mut rng);
+
+        let new_shares = reshare(&old_shares, 4, 2, 3, &mut rng).unwrap();
+
+        assert_eq!(reconstruct_with_threshold(&new_shares[1..], 2), Ok(secret));
+    }
+
+    #[test]
+    fn it_doesnt_reshare_without_enough_old_parties() {
+        let secret = Fq::from(31337);
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let old_shares = split(secret, 3, 5, &mut rng);
+
+        assert_eq!(
+            reshare(&old_shares[..2], 3, 3, 5, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 3,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_share_at_the_secret_index() {
+        let secret = Fq::from(31337);
+        let mut rng = StdRng::seed_from_u64(6);
+
+        let mut shares = split(secret, 3, 5, &mut rng);
+        shares[0] = Share::new(0, secret);
+
+        assert_eq!(
+            refresh_shares(&shares, 3, &mut rng),
+            Err(SecretSharingError::ZeroIndex)
+        );
+        assert_eq!(
+            reshare(&shares, 3, 3, 5, &mut rng),
+            Err(SecretSharingError::ZeroIndex)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_committees_without_panicking() {
+        let secret = Fq::from(31337);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let shares = split(secret, 3, 5, &mut rng);
+
+        assert_eq!(
+            refresh_shares(&shares[..2], 3, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 3,
+                received: 2
+            })
+        );
+        assert_eq!(
+            refresh_shares(&shares, 0, &mut rng),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+        assert_eq!(
+            reshare(&shares, 3, 0, 5, &mut rng),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+        assert_eq!(
+            reshare(&shares, 3, 4, 3, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 4,
+                received: 3
+            })
+        );
+    }
+}
@@ -1,103 +1,292 @@
-use ark_bn254::Fq;
-use ark_std::rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
+use ark_ff::{batch_inversion, PrimeField};
+use ark_std::rand::Rng;
+use std::{collections::HashSet, error::Error, fmt};
 use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
-fn create_polynomia(threshold: usize, secret_value: Fq, secret_point: Fq) -> UnivariatePoly<Fq> {
-    let mut points = vec![];
-    let base_point = (secret_point, secret_value);
+/// A single evaluation `(index, f(index))` of the dealer's polynomial. Indices run from 1 to n,
+/// the secret itself sits at 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Share<F: PrimeField> {
+    pub index: usize,
+    pub value: F,
+}
 
-    points.push(base_point);
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecretSharingError {
+    NoShares,
+    NoSecrets,
+    ZeroIndex,
+    ZeroThreshold,
+    IndexOutOfRange(usize),
+    NoFftDomain(usize),
+    LengthMismatch { left: usize, right: usize },
+    DuplicateIndex(usize),
+    NotEnoughShares { required: usize, received: usize },
+    TooManyErrors,
+}
 
-    let mut rng = StdRng::from_entropy();
+impl fmt::Display for SecretSharingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretSharingError::NoShares => write!(f, "no shares to reconstruct from"),
+            SecretSharingError::NoSecrets => write!(f, "no secrets to share"),
+            SecretSharingError::ZeroIndex => {
+                write!(f, "share index 0 is reserved for the secret")
+            }
+            SecretSharingError::ZeroThreshold => write!(f, "threshold must be at least 1"),
+            SecretSharingError::IndexOutOfRange(index) => {
+                write!(f, "share index {index} is past the last party")
+            }
+            SecretSharingError::NoFftDomain(size) => {
+                write!(f, "no power-of-two FFT domain of size {size}")
+            }
+            SecretSharingError::LengthMismatch { left, right } => {
+                write!(
+                    f,
+                    "share vectors have different lengths: {left} and {right}"
+                )
+            }
+            SecretSharingError::DuplicateIndex(index) => {
+                write!(f, "share index {index} appears more than once")
+            }
+            SecretSharingError::NotEnoughShares { required, received } => {
+                write!(f, "need {required} shares to reconstruct, got {received}")
+            }
+            SecretSharingError::TooManyErrors => {
+                write!(f, "too many corrupted shares to correct")
+            }
+        }
+    }
+}
 
-    for _ in 1..threshold {
-        let random_x_point = rng.gen();
-        let random_y_point = rng.gen();
+impl Error for SecretSharingError {}
 
-        points.push((random_x_point, random_y_point));
+impl<F: PrimeField> Share<F> {
+    pub fn new(index: usize, value: F) -> Self {
+        Self { index, value }
     }
 
-    let poly = UnivariatePoly::interpolate(points);
+    pub fn point(&self) -> (F, F) {
+        (F::from(self.index as u64), self.value)
+    }
+}
+
+pub fn split<F: PrimeField, R: Rng>(
+    secret: F,
+    threshold: usize,
+    num_of_shares: usize,
+    rng: &mut R,
+) -> Vec<Share<F>> {
+    let poly = create_polynomial(threshold, secret, rng);
 
-    poly
+    share_points(num_of_shares, threshold, &poly)
 }
 
-fn recover_polynomial(points: Vec<(Fq, Fq)>, threshold: usize) -> UnivariatePoly<Fq> {
-    if points.len() < threshold {
-        panic!("Not enough points to recreate polynomial");
+pub fn reconstruct<F: PrimeField>(shares: &[Share<F>]) -> Result<F, SecretSharingError> {
+    check_indices(shares)?;
+
+    Ok(interpolate_at(shares, F::zero()))
+}
+
+/// Recovers the secret from exactly `threshold` of the given shares. Any extra shares are ignored,
+/// so every subset of `threshold` honest shares gives the same secret.
+pub fn reconstruct_with_threshold<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+) -> Result<F, SecretSharingError> {
+    let selected_shares = select_shares(shares, threshold)?;
+
+    Ok(interpolate_at(selected_shares, F::zero()))
+}
+
+/// Evaluates the polynomial through `shares` at `point` directly from the Lagrange basis, without
+/// building its coefficients.
+pub fn interpolate_at<F: PrimeField>(shares: &[Share<F>], point: F) -> F {
+    let indices: Vec<usize> = shares.iter().map(|share| share.index).collect();
+
+    lagrange_coefficients_at(&indices, point)
+        .iter()
+        .zip(shares.iter())
+        .map(|(lambda, share)| *lambda * share.value)
+        .sum()
+}
+
+/// Returns `L_i(point)` for the Lagrange basis over the given share indices.
+pub fn lagrange_coefficients_at<F: PrimeField>(indices: &[usize], point: F) -> Vec<F> {
+    let xs: Vec<F> = indices.iter().map(|index| F::from(*index as u64)).collect();
+
+    lagrange_coefficients_over(&xs, point)
+}
+
+/// Returns `L_i(point)` for the Lagrange basis over arbitrary distinct points `xs`.
+pub fn lagrange_coefficients_over<F: PrimeField>(xs: &[F], point: F) -> Vec<F> {
+    let mut denominators: Vec<F> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, x_j)| *x_i - x_j)
+                .product()
+        })
+        .collect();
+
+    batch_inversion(&mut denominators);
+
+    xs.iter()
+        .enumerate()
+        .zip(denominators)
+        .map(|((i, _), denominator_inv)| {
+            let numerator: F = xs
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, x_j)| point - x_j)
+                .product();
+
+            numerator * denominator_inv
+        })
+        .collect()
+}
+
+pub fn create_polynomial<F: PrimeField, R: Rng>(
+    threshold: usize,
+    secret: F,
+    rng: &mut R,
+) -> UnivariatePoly<F> {
+    if threshold == 0 {
+        panic!("Threshold must be at least 1");
     }
 
-    let selected_points: Vec<(Fq, Fq)> = if points.len() > 3 {
-        points[0..4].to_vec()
-    } else {
-        points.clone()
-    };
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..threshold).map(|_| F::rand(rng)));
 
-    UnivariatePoly::interpolate(selected_points)
+    UnivariatePoly::new(coefficients)
 }
 
-fn get_secret(poly: &UnivariatePoly<Fq>, x_point: Fq) -> Fq {
-    let secret = poly.evaluate(Fq::from(x_point));
+pub fn recover_polynomial<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+) -> Result<UnivariatePoly<F>, SecretSharingError> {
+    let selected_shares = select_shares(shares, threshold)?;
+
+    Ok(UnivariatePoly::interpolate(
+        selected_shares.iter().map(Share::point).collect(),
+    ))
+}
 
-    secret
+pub fn get_secret<F: PrimeField>(poly: &UnivariatePoly<F>) -> F {
+    poly.evaluate(F::zero())
 }
 
-fn share_points(
+pub fn share_points<F: PrimeField>(
     num_of_shares: usize,
     threshold: usize,
-    poly: &UnivariatePoly<Fq>,
-) -> Vec<(Fq, Fq)> {
+    poly: &UnivariatePoly<F>,
+) -> Vec<Share<F>> {
     if num_of_shares < threshold {
         panic!("Num of shares too low")
     }
 
-    let mut rng = StdRng::from_entropy();
+    let indices: Vec<usize> = (1..=num_of_shares).collect();
 
-    let mut shares: Vec<(Fq, Fq)> = Vec::new();
+    share_points_at(&indices, poly)
+}
 
-    for _ in 0..num_of_shares {
-        let random_x_point = rng.gen();
+/// Evaluates the dealer's polynomial at the given party indices, for committees not numbered
+/// from 1.
+pub fn share_points_at<F: PrimeField>(
+    indices: &[usize],
+    poly: &UnivariatePoly<F>,
+) -> Vec<Share<F>> {
+    indices
+        .iter()
+        .map(|index| Share::new(*index, poly.evaluate(F::from(*index as u64))))
+        .collect()
+}
 
-        let y_point = poly.evaluate(random_x_point);
+pub(crate) fn select_shares<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: usize,
+) -> Result<&[Share<F>], SecretSharingError> {
+    check_indices(shares)?;
+
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
 
-        shares.push((random_x_point, y_point));
+    if shares.len() < threshold {
+        return Err(SecretSharingError::NotEnoughShares {
+            required: threshold,
+            received: shares.len(),
+        });
     }
 
-    shares
+    Ok(&shares[..threshold])
+}
+
+pub(crate) fn check_indices<F: PrimeField>(shares: &[Share<F>]) -> Result<(), SecretSharingError> {
+    let indices: Vec<usize> = shares.iter().map(|share| share.index).collect();
+
+    check_index_list(&indices)
+}
+
+pub(crate) fn check_index_list(indices: &[usize]) -> Result<(), SecretSharingError> {
+    if indices.is_empty() {
+        return Err(SecretSharingError::NoShares);
+    }
+
+    let mut seen = HashSet::with_capacity(indices.len());
+
+    for index in indices {
+        if *index == 0 {
+            return Err(SecretSharingError::ZeroIndex);
+        }
+
+        if !seen.insert(*index) {
+            return Err(SecretSharingError::DuplicateIndex(*index));
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
     use ark_bn254::Fq;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
     use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
-    use super::{create_polynomia, get_secret, recover_polynomial, share_points};
+    use super::{
+        create_polynomial, get_secret, interpolate_at, reconstruct, reconstruct_with_threshold,
+        recover_polynomial, share_points, split, SecretSharingError, Share,
+    };
 
     #[test]
     fn it_creates_a_correct_polynomial() {
         let threshold = 4;
         let secret_value = Fq::from(40);
-        let secret_point = Fq::from(6);
+        let mut rng = StdRng::seed_from_u64(1);
 
-        let mut polynomial = create_polynomia(threshold, secret_value, secret_point);
+        let polynomial = create_polynomial(threshold, secret_value, &mut rng);
 
-        let secret_evaluation = polynomial.evaluate(Fq::from(6));
+        let secret_evaluation = polynomial.evaluate(Fq::from(0));
 
-        assert_eq!(polynomial.degree(), 3);
+        assert_eq!(polynomial.degree(), Some(3));
         assert_eq!(secret_evaluation, Fq::from(40));
     }
 
     #[test]
     fn it_recreates_polynomial_with_valid_points() {
-        let points = vec![
-            (Fq::from(1), Fq::from(-1)),
-            (Fq::from(2), Fq::from(5)),
-            (Fq::from(3), Fq::from(13)),
+        let shares = vec![
+            Share::new(1, Fq::from(-1)),
+            Share::new(2, Fq::from(5)),
+            Share::new(3, Fq::from(13)),
         ];
         let threshold = 3;
 
-        let secret_poly = recover_polynomial(points, threshold);
+        let secret_poly = recover_polynomial(&shares, threshold).unwrap();
 
         assert_eq!(
             secret_poly.coefficient,
@@ -109,7 +298,7 @@ mod test {
     fn it_returns_right_secret() {
         let secret_poly = UnivariatePoly::new(vec![Fq::from(-5), Fq::from(3), Fq::from(1)]);
 
-        let secret_data = get_secret(&secret_poly, Fq::from(0));
+        let secret_data = get_secret(&secret_poly);
 
         assert_eq!(secret_data, Fq::from(-5));
     }
@@ -121,19 +310,24 @@ mod test {
         let shares = share_points(10, 3, &secret_poly);
 
         assert_eq!(shares.len(), 10);
+
+        for (i, share) in shares.iter().enumerate() {
+            assert_eq!(share.index, i + 1);
+            assert_eq!(share.value, secret_poly.evaluate(Fq::from(i as u64 + 1)));
+        }
     }
 
     #[test]
     fn it_doesnt_work_with_wrong_points() {
-        let wrong_point = (Fq::from(3), Fq::from(1));
+        let wrong_share = Share::new(3, Fq::from(1));
 
-        let points = vec![
-            (Fq::from(1), Fq::from(-1)),
-            (Fq::from(2), Fq::from(5)),
-            wrong_point,
+        let shares = vec![
+            Share::new(1, Fq::from(-1)),
+            Share::new(2, Fq::from(5)),
+            wrong_share,
         ];
 
-        let polynomial = recover_polynomial(points, 3);
+        let polynomial = recover_polynomial(&shares, 3).unwrap();
 
         assert_ne!(
             polynomial.coefficient,
@@ -143,28 +337,154 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn it_doesnt_generate_with_few_points() {
-        let points = vec![(Fq::from(1), Fq::from(-1)), (Fq::from(2), Fq::from(5))];
+        let shares = vec![Share::new(1, Fq::from(-1)), Share::new(2, Fq::from(5))];
 
-        let _ = recover_polynomial(points, 3);
+        assert_eq!(
+            recover_polynomial(&shares, 3).unwrap_err(),
+            SecretSharingError::NotEnoughShares {
+                required: 3,
+                received: 2
+            }
+        );
     }
 
     #[test]
     fn it_all_works_properly() {
-        let secret_point = Fq::from(0);
         let secret_data = Fq::from(-5);
-        let secret_poly = create_polynomia(3, secret_data, secret_point);
+        let mut rng = StdRng::seed_from_u64(2);
+        let secret_poly = create_polynomial(3, secret_data, &mut rng);
 
         let shares = share_points(10, 3, &secret_poly);
 
         let collected_shares = shares[2..6].to_vec();
 
-        let recreated_poly = recover_polynomial(collected_shares, 3);
+        let recreated_poly = recover_polynomial(&collected_shares, 3).unwrap();
 
-        let recovered_secret = get_secret(&recreated_poly, secret_point);
+        let recovered_secret = get_secret(&recreated_poly);
 
         assert_eq!(recreated_poly.coefficient, secret_poly.coefficient);
         assert_eq!(recovered_secret, secret_data);
     }
+
+    #[test]
+    fn it_splits_and_reconstructs() {
+        let secret = Fq::from(1234);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let shares = split(secret, 3, 6, &mut rng);
+
+        assert_eq!(reconstruct(&shares), Ok(secret));
+        assert_eq!(reconstruct(&shares[3..6]), Ok(secret));
+    }
+
+    #[test]
+    fn it_splits_deterministically_with_seeded_rng() {
+        let secret = Fq::from(1234);
+
+        let shares_1 = split(secret, 3, 5, &mut StdRng::seed_from_u64(4));
+        let shares_2 = split(secret, 3, 5, &mut StdRng::seed_from_u64(4));
+
+        assert_eq!(shares_1, shares_2);
+    }
+
+    #[test]
+    fn it_rejects_duplicate_indices() {
+        let secret = Fq::from(1234);
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let shares = split(secret, 3, 5, &mut rng);
+        let duplicated = vec![shares[0], shares[1], shares[1]];
+
+        assert_eq!(
+            reconstruct(&duplicated),
+            Err(SecretSharingError::DuplicateIndex(2))
+        );
+        assert_eq!(reconstruct::<Fq>(&[]), Err(SecretSharingError::NoShares));
+    }
+
+    #[test]
+    fn it_rejects_the_secret_index() {
+        let secret = Fq::from(1234);
+        let mut rng = StdRng::seed_from_u64(9);
+
+        let mut shares = split(secret, 3, 5, &mut rng);
+        shares[0] = Share::new(0, secret);
+
+        assert_eq!(reconstruct(&shares), Err(SecretSharingError::ZeroIndex));
+        assert_eq!(
+            reconstruct_with_threshold(&shares, 3),
+            Err(SecretSharingError::ZeroIndex)
+        );
+    }
+
+    #[test]
+    fn it_reconstructs_from_any_threshold_subset() {
+        let secret = Fq::from(99);
+        let mut rng = StdRng::seed_from_u64(6);
+
+        let shares = split(secret, 4, 7, &mut rng);
+
+        for start in 0..=3 {
+            let subset = &shares[start..start + 4];
+
+            assert_eq!(reconstruct_with_threshold(subset, 4), Ok(secret));
+        }
+
+        let scattered = vec![shares[6], shares[0], shares[4], shares[2], shares[5]];
+
+        assert_eq!(reconstruct_with_threshold(&scattered, 4), Ok(secret));
+    }
+
+    #[test]
+    fn it_rejects_too_few_or_duplicated_shares() {
+        let secret = Fq::from(99);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let shares = split(secret, 4, 7, &mut rng);
+
+        assert_eq!(
+            reconstruct_with_threshold(&shares[..3], 4),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 4,
+                received: 3
+            })
+        );
+
+        let duplicated = vec![shares[0], shares[1], shares[2], shares[0]];
+
+        assert_eq!(
+            reconstruct_with_threshold(&duplicated, 4),
+            Err(SecretSharingError::DuplicateIndex(1))
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_zero_threshold() {
+        let secret = Fq::from(99);
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let shares = split(secret, 3, 5, &mut rng);
+
+        assert_eq!(
+            reconstruct_with_threshold(&shares, 0),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+        assert_eq!(
+            recover_polynomial(&shares, 0),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+    }
+
+    #[test]
+    fn it_interpolates_at_any_point() {
+        let poly = UnivariatePoly::new(vec![Fq::from(-5), Fq::from(3), Fq::from(1)]);
+        let shares = share_points(3, 3, &poly);
+
+        assert_eq!(interpolate_at(&shares, Fq::from(0)), Fq::from(-5));
+        assert_eq!(
+            interpolate_at(&shares, Fq::from(10)),
+            poly.evaluate(Fq::from(10))
+        );
+    }
 }
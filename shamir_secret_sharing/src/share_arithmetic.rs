@@ -0,0 +1,265 @@
+use crate::{
+    proactive_refresh::reshare_to,
+    shamir_secret_sharing::{SecretSharingError, Share},
+};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use std::ops::{Add, Sub};
+
+impl<F: PrimeField> Share<F> {
+    pub fn scale(&self, scalar: F) -> Self {
+        Self::new(self.index, self.value * scalar)
+    }
+
+    /// Multiplies two shares held by the same party. The result lies on the product polynomial,
+    /// whose degree is the sum of both degrees, so it needs a degree reduction before the next
+    /// multiplication.
+    pub fn local_mul(&self, other: &Self) -> Self {
+        assert_same_index(self, other);
+
+        Self::new(self.index, self.value * other.value)
+    }
+}
+
+impl<F: PrimeField> Add for Share<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        assert_same_index(&self, &other);
+
+        Share::new(self.index, self.value + other.value)
+    }
+}
+
+impl<F: PrimeField> Sub for Share<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        assert_same_index(&self, &other);
+
+        Share::new(self.index, self.value - other.value)
+    }
+}
+
+fn assert_same_index<F: PrimeField>(a: &Share<F>, b: &Share<F>) {
+    if a.index != b.index {
+        panic!("shares belong to different parties");
+    }
+}
+
+/// BGW degree reduction: the product shares lie on a polynomial of degree `2 * (threshold - 1)`,
+/// so `2 * threshold - 1` parties each reshare their product share with the original threshold
+/// and everyone recombines with the Lagrange coefficients at zero. The reduced shares keep the
+/// indices of the parties holding `product_shares`.
+pub fn reduce_degree<F: PrimeField, R: Rng>(
+    product_shares: &[Share<F>],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    if threshold == 0 {
+        return Err(SecretSharingError::ZeroThreshold);
+    }
+
+    let required = 2 * threshold - 1;
+
+    if product_shares.len() < required {
+        return Err(SecretSharingError::NotEnoughShares {
+            required,
+            received: product_shares.len(),
+        });
+    }
+
+    let indices: Vec<usize> = product_shares.iter().map(|share| share.index).collect();
+
+    reshare_to(product_shares, required, threshold, &indices, rng)
+}
+
+/// Simulates one multiplication gate among the parties holding `a` and `b`: a local product
+/// followed by the degree reduction. Needs at least `2 * threshold - 1` parties.
+pub fn multiply_shares<F: PrimeField, R: Rng>(
+    a: &[Share<F>],
+    b: &[Share<F>],
+    threshold: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, SecretSharingError> {
+    if a.len() != b.len() {
+        return Err(SecretSharingError::LengthMismatch {
+            left: a.len(),
+            right: b.len(),
+        });
+    }
+
+    let product_shares: Vec<Share<F>> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(share_a, share_b)| share_a.local_mul(share_b))
+        .collect();
+
+    reduce_degree(&product_shares, threshold, rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{multiply_shares, reduce_degree};
+    use crate::shamir_secret_sharing::{
+        reconstruct_with_threshold, split, SecretSharingError, Share,
+    };
+    use ark_bn254::Fq;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn it_multiplies_shared_values() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let shares_x = split(Fq::from(6), 3, 5, &mut rng);
+        let shares_y = split(Fq::from(7), 3, 5, &mut rng);
+
+        let product = multiply_shares(&shares_x, &shares_y, 3, &mut rng).unwrap();
+
+        assert_eq!(product.len(), 5);
+        assert_eq!(reconstruct_with_threshold(&product, 3), Ok(Fq::from(42)));
+        assert_eq!(
+            reconstruct_with_threshold(&product[2..], 3),
+            Ok(Fq::from(42))
+        );
+    }
+
+    #[test]
+    fn it_evaluates_a_small_circuit() {
+        // (x + y) * z + 3x, with every party only ever touching its own shares.
+        let mut rng = StdRng::seed_from_u64(2);
+        let threshold = 2;
+
+        let shares_x = split(Fq::from(2), threshold, 4, &mut rng);
+        let shares_y = split(Fq::from(5), threshold, 4, &mut rng);
+        let shares_z = split(Fq::from(10), threshold, 4, &mut rng);
+
+        let sum: Vec<_> = shares_x
+            .iter()
+            .zip(shares_y.iter())
+            .map(|(x, y)| *x + *y)
+            .collect();
+
+        let product = multiply_shares(&sum, &shares_z, threshold, &mut rng).unwrap();
+
+        let result: Vec<_> = product
+            .iter()
+            .zip(shares_x.iter())
+            .map(|(p, x)| *p + x.scale(Fq::from(3)))
+            .collect();
+
+        let second_product = multiply_shares(&result, &shares_z, threshold, &mut rng).unwrap();
+
+        assert_eq!(
+            reconstruct_with_threshold(&result, threshold),
+            Ok(Fq::from(76))
+        );
+        assert_eq!(
+            reconstruct_with_threshold(&second_product, threshold),
+            Ok(Fq::from(760))
+        );
+    }
+
+    #[test]
+    fn it_needs_enough_parties_to_reduce_degree() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let shares_x = split(Fq::from(6), 3, 4, &mut rng);
+        let shares_y = split(Fq::from(7), 3, 4, &mut rng);
+
+        assert_eq!(
+            multiply_shares(&shares_x, &shares_y, 3, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 5,
+                received: 4
+            })
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_indices_of_a_committee_not_starting_at_one() {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let shares_x = split(Fq::from(6), 2, 6, &mut rng);
+        let shares_y = split(Fq::from(7), 2, 6, &mut rng);
+
+        let product = multiply_shares(&shares_x[3..], &shares_y[3..], 2, &mut rng).unwrap();
+
+        let indices: Vec<usize> = product.iter().map(|share| share.index).collect();
+        assert_eq!(indices, vec![4, 5, 6]);
+
+        let sum: Vec<_> = product
+            .iter()
+            .zip(shares_x[3..].iter())
+            .map(|(p, x)| *p + *x)
+            .collect();
+
+        assert_eq!(reconstruct_with_threshold(&product, 2), Ok(Fq::from(42)));
+        assert_eq!(reconstruct_with_threshold(&sum, 2), Ok(Fq::from(48)));
+    }
+
+    #[test]
+    fn it_rejects_share_vectors_of_different_lengths() {
+        let mut rng = StdRng::seed_from_u64(6);
+
+        let shares_x = split(Fq::from(6), 2, 4, &mut rng);
+        let shares_y = split(Fq::from(7), 2, 4, &mut rng);
+
+        assert_eq!(
+            multiply_shares(&shares_x, &shares_y[..3], 2, &mut rng),
+            Err(SecretSharingError::LengthMismatch { left: 4, right: 3 })
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_degree_reductions() {
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let shares = split(Fq::from(6), 3, 5, &mut rng);
+
+        assert_eq!(
+            reduce_degree(&shares, 0, &mut rng),
+            Err(SecretSharingError::ZeroThreshold)
+        );
+        assert_eq!(
+            reduce_degree::<Fq, _>(&[], 2, &mut rng),
+            Err(SecretSharingError::NotEnoughShares {
+                required: 3,
+                received: 0
+            })
+        );
+    }
+
+    #[test]
+    fn it_adds_and_scales_shares_locally() {
+        let mut rng = StdRng::seed_from_u64(8);
+
+        let shares_a = split(Fq::from(10), 3, 5, &mut rng);
+        let shares_b = split(Fq::from(32), 3, 5, &mut rng);
+
+        let sum: Vec<_> = shares_a
+            .iter()
+            .zip(shares_b.iter())
+            .map(|(a, b)| *a + *b)
+            .collect();
+        let difference: Vec<_> = shares_a
+            .iter()
+            .zip(shares_b.iter())
+            .map(|(a, b)| *a - *b)
+            .collect();
+        let scaled: Vec<_> = shares_a.iter().map(|a| a.scale(Fq::from(3))).collect();
+
+        assert_eq!(reconstruct_with_threshold(&sum, 3), Ok(Fq::from(42)));
+        assert_eq!(
+            reconstruct_with_threshold(&difference, 3),
+            Ok(Fq::from(-22))
+        );
+        assert_eq!(reconstruct_with_threshold(&scaled, 3), Ok(Fq::from(30)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_doesnt_add_shares_of_different_parties() {
+        let _ = Share::new(1, Fq::from(1)) + Share::new(2, Fq::from(1));
+    }
+}
@@ -0,0 +1,72 @@
+use ark_ff::{
+    fields::{Fp2, Fp2Config, Fp4, Fp4Config, Fp64, MontBackend, MontConfig},
+    MontFp,
+};
+
+/// The BabyBear prime `p = 15 * 2^27 + 1`, with two-adicity 27.
+#[derive(MontConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+pub struct BabyBearConfig;
+pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+/// `BabyBear[u] / (u^2 - 11)`.
+pub struct BabyBear2Config;
+
+impl Fp2Config for BabyBear2Config {
+    type Fp = BabyBear;
+
+    const NONRESIDUE: BabyBear = MontFp!("11");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [BabyBear] = &[MontFp!("1"), MontFp!("-1")];
+}
+
+pub type BabyBear2 = Fp2<BabyBear2Config>;
+
+/// `BabyBear2[v] / (v^2 - u)`, i.e. `BabyBear[v] / (v^4 - 11)`.
+pub struct BabyBear4Config;
+
+impl Fp4Config for BabyBear4Config {
+    type Fp2Config = BabyBear2Config;
+
+    const NONRESIDUE: BabyBear2 = BabyBear2::new(MontFp!("0"), MontFp!("1"));
+
+    // 11^((p^i - 1) / 4) for i = 0..4
+    const FROBENIUS_COEFF_FP4_C1: &'static [BabyBear] = &[
+        MontFp!("1"),
+        MontFp!("1728404513"),
+        MontFp!("-1"),
+        MontFp!("284861408"),
+    ];
+}
+
+pub type BabyBear4 = Fp4<BabyBear4Config>;
+
+#[cfg(test)]
+mod test {
+    use super::{BabyBear, BabyBear2, BabyBear4};
+    use ark_ff::{FftField, Field, UniformRand};
+
+    #[test]
+    fn it_has_the_expected_two_adicity() {
+        assert_eq!(BabyBear::TWO_ADICITY, 27);
+
+        let root = BabyBear::get_root_of_unity(1 << 27).unwrap();
+        assert_eq!(root.pow([1u64 << 26]), -BabyBear::from(1));
+    }
+
+    #[test]
+    fn it_satisfies_the_extension_field_axioms() {
+        let mut rng = ark_std::test_rng();
+
+        let a = BabyBear4::rand(&mut rng);
+        let b = BabyBear4::rand(&mut rng);
+        assert_eq!(a * b * b.inverse().unwrap(), a);
+        assert_eq!(a.frobenius_map(4), a);
+        assert_eq!(a.frobenius_map(1), a.pow(BabyBear::characteristic()));
+
+        let c = BabyBear2::rand(&mut rng);
+        assert_eq!(c.frobenius_map(1), c.pow(BabyBear::characteristic()));
+        assert_eq!(BabyBear4::extension_degree(), 4);
+    }
+}
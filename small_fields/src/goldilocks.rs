@@ -0,0 +1,72 @@
+use ark_ff::{
+    fields::{Fp2, Fp2Config, Fp4, Fp4Config, Fp64, MontBackend, MontConfig},
+    MontFp,
+};
+
+/// The Goldilocks prime `p = 2^64 - 2^32 + 1`, with two-adicity 32.
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+/// `Goldilocks[u] / (u^2 - 7)`.
+pub struct Goldilocks2Config;
+
+impl Fp2Config for Goldilocks2Config {
+    type Fp = Goldilocks;
+
+    const NONRESIDUE: Goldilocks = MontFp!("7");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Goldilocks] = &[MontFp!("1"), MontFp!("-1")];
+}
+
+pub type Goldilocks2 = Fp2<Goldilocks2Config>;
+
+/// `Goldilocks2[v] / (v^2 - u)`, i.e. `Goldilocks[v] / (v^4 - 7)`.
+pub struct Goldilocks4Config;
+
+impl Fp4Config for Goldilocks4Config {
+    type Fp2Config = Goldilocks2Config;
+
+    const NONRESIDUE: Goldilocks2 = Goldilocks2::new(MontFp!("0"), MontFp!("1"));
+
+    // 7^((p^i - 1) / 4) for i = 0..4
+    const FROBENIUS_COEFF_FP4_C1: &'static [Goldilocks] = &[
+        MontFp!("1"),
+        MontFp!("281474976710656"),
+        MontFp!("-1"),
+        MontFp!("18446462594437873665"),
+    ];
+}
+
+pub type Goldilocks4 = Fp4<Goldilocks4Config>;
+
+#[cfg(test)]
+mod test {
+    use super::{Goldilocks, Goldilocks2, Goldilocks4};
+    use ark_ff::{FftField, Field, UniformRand};
+
+    #[test]
+    fn it_has_the_expected_two_adicity() {
+        assert_eq!(Goldilocks::TWO_ADICITY, 32);
+
+        let root = Goldilocks::get_root_of_unity(1 << 32).unwrap();
+        assert_eq!(root.pow([1u64 << 31]), -Goldilocks::from(1));
+    }
+
+    #[test]
+    fn it_satisfies_the_extension_field_axioms() {
+        let mut rng = ark_std::test_rng();
+
+        let a = Goldilocks4::rand(&mut rng);
+        let b = Goldilocks4::rand(&mut rng);
+        assert_eq!(a * b * b.inverse().unwrap(), a);
+        assert_eq!(a.frobenius_map(4), a);
+        assert_eq!(a.frobenius_map(1), a.pow(Goldilocks::characteristic()));
+
+        let c = Goldilocks2::rand(&mut rng);
+        assert_eq!(c.frobenius_map(1), c.pow(Goldilocks::characteristic()));
+        assert_eq!(Goldilocks4::extension_degree(), 4);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod babybear;
+pub mod goldilocks;
+pub mod mersenne31;
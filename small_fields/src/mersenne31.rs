@@ -0,0 +1,95 @@
+use ark_ff::{
+    fields::{Fp2, Fp2Config, Fp64, MontBackend, MontConfig, QuadExtConfig, QuadExtField},
+    MontFp,
+};
+
+/// The Mersenne prime `p = 2^31 - 1`. Its two-adicity is only 1, so the
+/// `fft` crate reaches it through the small subgroup of order 3^2.
+#[derive(MontConfig)]
+#[modulus = "2147483647"]
+#[generator = "7"]
+#[small_subgroup_base = "3"]
+#[small_subgroup_power = "2"]
+pub struct Mersenne31Config;
+pub type Mersenne31 = Fp64<MontBackend<Mersenne31Config, 1>>;
+
+/// The complex extension `Mersenne31[i] / (i^2 + 1)`; `-1` is a non-residue
+/// since `p = 3 mod 4`.
+pub struct Mersenne31ComplexConfig;
+
+impl Fp2Config for Mersenne31ComplexConfig {
+    type Fp = Mersenne31;
+
+    const NONRESIDUE: Mersenne31 = MontFp!("-1");
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Mersenne31] = &[MontFp!("1"), MontFp!("-1")];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Mersenne31) -> &mut Mersenne31 {
+        *fe = -*fe;
+        fe
+    }
+}
+
+pub type Mersenne31Complex = Fp2<Mersenne31ComplexConfig>;
+
+/// `Mersenne31Complex[v] / (v^2 - (2 + i))`. The `Fp4Config` tower of ark-ff
+/// requires the non-residue `i`, which is a square here, so the quartic
+/// extension is configured directly.
+pub struct Mersenne31QuarticConfig;
+
+impl QuadExtConfig for Mersenne31QuarticConfig {
+    type BasePrimeField = Mersenne31;
+    type BaseField = Mersenne31Complex;
+    type FrobCoeff = Mersenne31Complex;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 4;
+
+    const NONRESIDUE: Mersenne31Complex = Mersenne31Complex::new(MontFp!("2"), MontFp!("1"));
+
+    // (2 + i)^((p^k - 1) / 2) for k = 0..4
+    const FROBENIUS_COEFF_C1: &'static [Mersenne31Complex] = &[
+        Mersenne31Complex::new(MontFp!("1"), MontFp!("0")),
+        Mersenne31Complex::new(MontFp!("21189756"), MontFp!("42379512")),
+        Mersenne31Complex::new(MontFp!("-1"), MontFp!("0")),
+        Mersenne31Complex::new(MontFp!("2126293891"), MontFp!("2105104135")),
+    ];
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Mersenne31Complex, power: usize) {
+        *fe *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
+
+pub type Mersenne31Quartic = QuadExtField<Mersenne31QuarticConfig>;
+
+#[cfg(test)]
+mod test {
+    use super::{Mersenne31, Mersenne31Complex, Mersenne31Quartic};
+    use ark_ff::{FftField, Field, UniformRand};
+
+    #[test]
+    fn it_has_a_small_subgroup_of_order_nine() {
+        assert_eq!(Mersenne31::TWO_ADICITY, 1);
+
+        let root = Mersenne31::get_root_of_unity(18).unwrap();
+        assert_eq!(root.pow([18u64]), Mersenne31::from(1));
+        assert_ne!(root.pow([9u64]), Mersenne31::from(1));
+        assert_ne!(root.pow([6u64]), Mersenne31::from(1));
+    }
+
+    #[test]
+    fn it_satisfies_the_extension_field_axioms() {
+        let mut rng = ark_std::test_rng();
+
+        let a = Mersenne31Quartic::rand(&mut rng);
+        let b = Mersenne31Quartic::rand(&mut rng);
+        assert_eq!(a * b * b.inverse().unwrap(), a);
+        assert_eq!(a.frobenius_map(4), a);
+        assert_eq!(a.frobenius_map(1), a.pow(Mersenne31::characteristic()));
+
+        let c = Mersenne31Complex::rand(&mut rng);
+        assert_eq!(c.square(), c * c);
+        assert_eq!(c.frobenius_map(1), c.pow(Mersenne31::characteristic()));
+        assert_eq!(Mersenne31Quartic::extension_degree(), 4);
+    }
+}
@@ -1,7 +1,15 @@
 use ark_bn254::Fq;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ark_ff::{Field, PrimeField};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
 use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
-use sum_check::sum_check_protocol::{prove, verify};
+use small_fields::{
+    babybear::{BabyBear, BabyBear4},
+    goldilocks::{Goldilocks, Goldilocks2},
+    mersenne31::{Mersenne31, Mersenne31Quartic},
+};
+use sum_check::sum_check_protocol::{prove, prove_ext, verify, Proof};
 
 /// Benchmarks the proving procedure of the sum-check protocol.
 /// This benchmark creates a fixed multilinear polynomial and repeatedly
@@ -58,5 +66,39 @@ pub fn verify_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, prove_benchmark, verify_benchmark);
+fn bench_prove_ext<F: PrimeField, E: Field<BasePrimeField = F>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+) {
+    let evaluations: Vec<F> = (0..1u64 << 16).map(F::from).collect();
+    let poly = MultilinearPoly::new(evaluations);
+
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            let proof: Proof<E> = prove_ext(black_box(&poly));
+            black_box(proof);
+        })
+    });
+}
+
+/// Benchmarks proving a 16-variable polynomial over BN254 and over the small fields, where the
+/// witness stays in the base field and challenges come from an extension of about 128 bits.
+pub fn small_field_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SumCheck Prove 2^16");
+    group.sample_size(10);
+
+    bench_prove_ext::<Fq, Fq>(&mut group, "bn254");
+    bench_prove_ext::<Goldilocks, Goldilocks2>(&mut group, "goldilocks^2");
+    bench_prove_ext::<BabyBear, BabyBear4>(&mut group, "babybear^4");
+    bench_prove_ext::<Mersenne31, Mersenne31Quartic>(&mut group, "mersenne31^4");
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    prove_benchmark,
+    verify_benchmark,
+    small_field_benchmark
+);
 criterion_main!(benches);
@@ -1,12 +1,12 @@
-use ark_ff::PrimeField;
-use fiat_shamir::fiat_shamir_transcript::{fq_vec_to_bytes, Transcript};
+use ark_ff::{Field, PrimeField};
+use fiat_shamir::fiat_shamir_transcript::{field_vec_to_bytes, fq_vec_to_bytes, Transcript};
 use multilinear_polynomial::{
     composed_polynomial::SumPoly, multilinear_polynomial_evaluation::MultilinearPoly,
 };
 use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
 #[derive(Debug, Clone)]
-pub struct Proof<F: PrimeField> {
+pub struct Proof<F: Field> {
     pub proof_polynomials: Vec<Vec<F>>,
     pub claimed_sum: F,
 }
@@ -23,26 +23,51 @@ pub struct GkrVerify<F: PrimeField> {
 }
 
 pub fn prove<F: PrimeField>(polynomial: &MultilinearPoly<F>) -> Proof<F> {
+    prove_ext(polynomial)
+}
+
+pub fn verify<F: PrimeField>(polynomial: &MultilinearPoly<F>, proof: Proof<F>) -> bool {
+    verify_ext(polynomial, proof)
+}
+
+/// Proves the sum of a base-field polynomial with challenges drawn from an
+/// extension `E`. The first round is summed in `F` and its fold is mixed
+/// base×extension; only the remaining, already halved rounds run in `E`.
+pub fn prove_ext<F: PrimeField, E: Field<BasePrimeField = F>>(
+    polynomial: &MultilinearPoly<F>,
+) -> Proof<E> {
     let mut transcript = Transcript::<F>::new();
     transcript.append(&fq_vec_to_bytes(&polynomial.evaluation));
 
-    let claimed_sum: F = polynomial.evaluation.iter().sum();
-    transcript.append(&fq_vec_to_bytes(&[claimed_sum]));
+    let claimed_sum = E::from_base_prime_field(polynomial.evaluation.iter().sum());
+    transcript.append(&field_vec_to_bytes(&[claimed_sum]));
 
     let num_rounds = polynomial.num_of_vars;
-    let mut proof_polynomials = Vec::with_capacity(num_rounds as usize);
-    let mut current_poly = polynomial.clone();
+    let mut proof_polynomials = Vec::with_capacity(num_rounds);
+    let mut current_poly: Option<MultilinearPoly<E>> = None;
 
     for _ in 0..num_rounds {
-        let proof_poly = get_round_partial_polynomial_proof(&current_poly.evaluation);
+        let proof_poly: Vec<E> = match &current_poly {
+            None => get_round_partial_polynomial_proof(&polynomial.evaluation)
+                .into_iter()
+                .map(E::from_base_prime_field)
+                .collect(),
+            Some(poly) => get_round_partial_polynomial_proof(&poly.evaluation),
+        };
 
-        transcript.append(&fq_vec_to_bytes(&proof_poly));
+        transcript.append(&field_vec_to_bytes(&proof_poly));
 
         proof_polynomials.push(proof_poly);
 
-        let random_challenge = transcript.get_random_challenge();
+        let random_challenge: E = transcript.get_random_extension_challenge();
 
-        current_poly = current_poly.partial_evaluate(0, &random_challenge);
+        current_poly = Some(match current_poly {
+            None => polynomial.partial_evaluate_ext(0, &random_challenge),
+            Some(mut poly) => {
+                poly.fix_first_variable_in_place(&random_challenge);
+                poly
+            }
+        });
     }
 
     Proof {
@@ -51,36 +76,35 @@ pub fn prove<F: PrimeField>(polynomial: &MultilinearPoly<F>) -> Proof<F> {
     }
 }
 
-pub fn verify<F: PrimeField>(polynomial: &MultilinearPoly<F>, proof: Proof<F>) -> bool {
+pub fn verify_ext<F: PrimeField, E: Field<BasePrimeField = F>>(
+    polynomial: &MultilinearPoly<F>,
+    proof: Proof<E>,
+) -> bool {
     let mut transcript = Transcript::<F>::new();
     transcript.append(&fq_vec_to_bytes(&polynomial.evaluation));
-    transcript.append(&fq_vec_to_bytes(&[proof.claimed_sum]));
+    transcript.append(&field_vec_to_bytes(&[proof.claimed_sum]));
 
-    let mut current_poly = polynomial.clone();
     let mut random_challenges = Vec::with_capacity(proof.proof_polynomials.len());
     let mut expected_sum = proof.claimed_sum;
 
     for poly in proof.proof_polynomials {
-        let poly = MultilinearPoly::new(poly.to_vec());
-
-        if poly.evaluation.iter().sum::<F>() != expected_sum {
+        if poly.len() != 2 || poly[0] + poly[1] != expected_sum {
             return false;
         }
 
-        transcript.append(&fq_vec_to_bytes(&poly.evaluation));
-        let random_challenge = transcript.get_random_challenge();
-
-        expected_sum =
-            poly.evaluation[0] + random_challenge * (poly.evaluation[1] - poly.evaluation[0]);
+        transcript.append(&field_vec_to_bytes(&poly));
+        let random_challenge: E = transcript.get_random_extension_challenge();
 
-        current_poly = current_poly.partial_evaluate(0, &random_challenge);
+        expected_sum = poly[0] + random_challenge * (poly[1] - poly[0]);
 
         random_challenges.push(random_challenge);
     }
 
-    let poly_eval_sum = polynomial.evaluate(random_challenges);
+    if random_challenges.len() != polynomial.num_of_vars {
+        return false;
+    }
 
-    expected_sum == poly_eval_sum
+    expected_sum == polynomial.evaluate_ext(&random_challenges)
 }
 
 pub fn gkr_prove<F: PrimeField>(
@@ -165,7 +189,7 @@ fn get_round_partial_polynomial_proof_gkr<F: PrimeField>(
     UnivariatePoly::interpolate(points)
 }
 
-fn get_round_partial_polynomial_proof<F: PrimeField>(polynomial: &[F]) -> Vec<F> {
+fn get_round_partial_polynomial_proof<F: Field>(polynomial: &[F]) -> Vec<F> {
     let mid_point = polynomial.len() / 2;
     let (zeros, ones) = polynomial.split_at(mid_point);
 
@@ -176,15 +200,21 @@ fn get_round_partial_polynomial_proof<F: PrimeField>(polynomial: &[F]) -> Vec<F>
 
 #[cfg(test)]
 mod test {
+    use ark_ff::{Field, PrimeField};
     use fiat_shamir::fiat_shamir_transcript::Transcript;
     use field_tracker::{print_summary, Ft};
     use multilinear_polynomial::{
         composed_polynomial::{ProductPoly, SumPoly},
         multilinear_polynomial_evaluation::MultilinearPoly,
     };
+    use small_fields::{
+        babybear::{BabyBear, BabyBear4},
+        goldilocks::{Goldilocks, Goldilocks2},
+        mersenne31::{Mersenne31, Mersenne31Quartic},
+    };
     use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
 
-    use crate::sum_check_protocol::{prove, verify, Proof};
+    use crate::sum_check_protocol::{prove, prove_ext, verify, verify_ext, Proof};
 
     use super::{get_round_partial_polynomial_proof_gkr, gkr_prove, gkr_verify};
 
@@ -267,4 +297,38 @@ mod test {
 
         assert_eq!(verified.verified, true);
     }
+
+    fn proves_and_verifies_over_extension<F: PrimeField, E: Field<BasePrimeField = F>>() {
+        let evaluations = (0..16u64).map(|i| F::from(i * i + 3)).collect();
+        let polynomial = MultilinearPoly::new(evaluations);
+
+        let proof: Proof<E> = prove_ext(&polynomial);
+        assert_eq!(proof.claimed_sum, E::from(1288u64));
+        assert!(verify_ext(&polynomial, proof.clone()));
+
+        // Shifting weight between the halves keeps the round sum but breaks the next round.
+        let shift =
+            E::from_base_prime_field_elems((0..E::extension_degree()).map(|j| F::from(j + 1)))
+                .unwrap();
+        let mut tampered = proof;
+        tampered.proof_polynomials[2][0] += shift;
+        tampered.proof_polynomials[2][1] -= shift;
+        assert!(!verify_ext(&polynomial, tampered));
+    }
+
+    #[test]
+    fn test_extension_field_proving_and_verification() {
+        proves_and_verifies_over_extension::<ark_bn254::Fq, ark_bn254::Fq2>();
+    }
+
+    #[test]
+    fn test_small_field_proving_and_verification() {
+        proves_and_verifies_over_extension::<Goldilocks, Goldilocks>();
+        proves_and_verifies_over_extension::<BabyBear, BabyBear>();
+        proves_and_verifies_over_extension::<Mersenne31, Mersenne31>();
+
+        proves_and_verifies_over_extension::<Goldilocks, Goldilocks2>();
+        proves_and_verifies_over_extension::<BabyBear, BabyBear4>();
+        proves_and_verifies_over_extension::<Mersenne31, Mersenne31Quartic>();
+    }
 }
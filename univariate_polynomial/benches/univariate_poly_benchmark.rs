@@ -1,6 +1,8 @@
-use ark_bn254::Fq;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use univariate_polynomial::univariate_polynomial_dense::UnivariatePoly;
+use ark_bn254::{Fq, Fr};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use univariate_polynomial::{
+    multipoint::SubproductTree, univariate_polynomial_dense::UnivariatePoly,
+};
 
 /// Benchmarks the evaluation of a univariate polynomial.
 /// In this example, we create a polynomial with 100 coefficients
@@ -22,6 +24,50 @@ pub fn evaluate_benchmark(c: &mut Criterion) {
     });
 }
 
+/// Compares Horner evaluation with the chunked evaluation on large polynomials. Run with
+/// `--features parallel` to spread the chunks across threads.
+pub fn large_evaluate_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("UnivariatePoly large evaluate");
+    group.sample_size(10);
+
+    for log_size in [12, 16, 20] {
+        let n = 1usize << log_size;
+        let poly = UnivariatePoly::new((0..n).map(|i| Fr::from(i as u64)).collect());
+        let x = Fr::from(7);
+
+        group.bench_with_input(BenchmarkId::new("horner", n), &n, |b, _| {
+            b.iter(|| black_box(poly.evaluate(black_box(x))))
+        });
+        group.bench_with_input(BenchmarkId::new("chunked", n), &n, |b, _| {
+            b.iter(|| black_box(poly.evaluate_chunked(black_box(x))))
+        });
+    }
+
+    group.finish();
+}
+
+/// Evaluates a polynomial with `n` coefficients at `n` points one at a time with `evaluate_many`,
+/// against the subproduct tree used by `multipoint_evaluate`. The sizes run past the crossover
+/// that sets `TREE_EVALUATION_THRESHOLD`.
+pub fn evaluate_many_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("UnivariatePoly evaluate many");
+    group.sample_size(10);
+
+    for n in [256, 1024, 1536, 2048, 4096, 8192] {
+        let poly = UnivariatePoly::new((0..n).map(|i| Fr::from(i as u64 + 1)).collect());
+        let points: Vec<Fr> = (0..n).map(|i| Fr::from(3 * i as u64 + 2)).collect();
+
+        group.bench_with_input(BenchmarkId::new("evaluate_many", n), &n, |b, _| {
+            b.iter(|| black_box(poly.evaluate_many(black_box(&points))))
+        });
+        group.bench_with_input(BenchmarkId::new("subproduct tree", n), &n, |b, _| {
+            b.iter(|| black_box(SubproductTree::new(&points).evaluate(black_box(&poly))))
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmarks the interpolation of a univariate polynomial from points.
 /// Here, we generate 10 points that lie on a simple linear function,
 /// and then interpolate to recover the polynomial.
@@ -44,5 +90,58 @@ pub fn interpolation_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, evaluate_benchmark, interpolation_benchmark);
+/// Compares schoolbook and NTT multiplication of two polynomials with `n` coefficients each,
+/// over BN254's scalar field which has large power-of-two domains.
+pub fn multiplication_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("UnivariatePoly mul");
+
+    for n in [16, 64, 256, 1024] {
+        let poly_1 = UnivariatePoly::new((0..n).map(|i| Fr::from(i as u64 + 1)).collect());
+        let poly_2 = UnivariatePoly::new((0..n).map(|i| Fr::from(3 * i as u64 + 2)).collect());
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |b, _| {
+            b.iter(|| black_box(poly_1.naive_mul(black_box(&poly_2))))
+        });
+        group.bench_with_input(BenchmarkId::new("fft", n), &n, |b, _| {
+            b.iter(|| black_box(poly_1.fft_mul(black_box(&poly_2)).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+/// Interpolates `n` points with `UnivariatePoly::interpolate`, which goes through the
+/// subproduct tree at these sizes, and evaluates the result back at the same points with
+/// `multipoint_evaluate`.
+pub fn multipoint_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("UnivariatePoly multipoint");
+    group.sample_size(10);
+
+    for n in [64, 256, 1024] {
+        let points: Vec<(Fr, Fr)> = (0..n)
+            .map(|i| (Fr::from(i as u64), Fr::from((i * i * i) as u64 + 1)))
+            .collect();
+        let xs: Vec<Fr> = points.iter().map(|(x, _)| *x).collect();
+        let poly = UnivariatePoly::interpolate(points.clone());
+
+        group.bench_with_input(BenchmarkId::new("interpolate", n), &n, |b, _| {
+            b.iter(|| black_box(UnivariatePoly::interpolate(black_box(points.clone()))))
+        });
+        group.bench_with_input(BenchmarkId::new("evaluate", n), &n, |b, _| {
+            b.iter(|| black_box(poly.multipoint_evaluate(black_box(&xs))))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    evaluate_benchmark,
+    large_evaluate_benchmark,
+    evaluate_many_benchmark,
+    interpolation_benchmark,
+    multiplication_benchmark,
+    multipoint_benchmark
+);
 criterion_main!(benches);
@@ -1 +1,4 @@
+pub mod multipoint;
 pub mod univariate_polynomial_dense;
+pub mod univariate_polynomial_evaluation;
+pub mod univariate_polynomial_sparse;
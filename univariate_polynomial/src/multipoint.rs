@@ -0,0 +1,158 @@
+use ark_ff::FftField;
+
+use crate::univariate_polynomial_dense::UnivariatePoly;
+
+/// Binary tree of the products `prod (x - x_i)` over ever larger groups of points: the leaves
+/// are the linear factors `x - x_i` and the root is the vanishing polynomial of all the points.
+/// Walking remainders down the tree evaluates a polynomial at every point, and combining
+/// weighted leaves back up interpolates, both with `O(n log^2 n)` field operations once
+/// multiplication and division go through the NTT.
+#[derive(Debug, Clone)]
+pub struct SubproductTree<F: FftField> {
+    pub points: Vec<F>,
+    layers: Vec<Vec<UnivariatePoly<F>>>,
+}
+
+impl<F: FftField> SubproductTree<F> {
+    pub fn new(points: &[F]) -> Self {
+        let leaves: Vec<UnivariatePoly<F>> = points
+            .iter()
+            .map(|x| UnivariatePoly::new(vec![-*x, F::one()]))
+            .collect();
+
+        let mut layers = vec![leaves];
+
+        while layers.last().map_or(0, Vec::len) > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    _ => pair[0].clone(),
+                })
+                .collect();
+
+            layers.push(next);
+        }
+
+        SubproductTree {
+            points: points.to_vec(),
+            layers,
+        }
+    }
+
+    /// The vanishing polynomial `prod (x - x_i)` of all the points.
+    pub fn root(&self) -> UnivariatePoly<F> {
+        match self.layers.last().and_then(|layer| layer.first()) {
+            Some(root) => root.clone(),
+            None => UnivariatePoly::new(vec![F::one()]),
+        }
+    }
+
+    /// Evaluates `poly` at every point, in the order the points were given.
+    pub fn evaluate(&self, poly: &UnivariatePoly<F>) -> Vec<F> {
+        if self.points.is_empty() {
+            return vec![];
+        }
+
+        let mut remainders = vec![poly.div_rem(&self.root()).1];
+
+        // A node's children sit at indices 2i and 2i + 1 of the layer below it.
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].div_rem(node).1)
+                .collect();
+        }
+
+        remainders
+            .iter()
+            .map(|r| r.coefficient.first().copied().unwrap_or(F::zero()))
+            .collect()
+    }
+
+    /// Returns the unique polynomial of degree below the number of points taking `values[i]` at
+    /// the `i`-th point. Panics if the points are not distinct.
+    pub fn interpolate(&self, values: &[F]) -> UnivariatePoly<F> {
+        assert_eq!(
+            values.len(),
+            self.points.len(),
+            "Need exactly one value per point"
+        );
+
+        if values.is_empty() {
+            return UnivariatePoly::new(vec![]);
+        }
+
+        // Lagrange weights y_i / M'(x_i), with M the vanishing polynomial of the points.
+        let derivative_evals = self.evaluate(&self.root().derivative());
+
+        let mut combinations: Vec<UnivariatePoly<F>> = values
+            .iter()
+            .zip(derivative_evals)
+            .map(|(y, d)| {
+                let d_inv = d.inverse().expect("Interpolation points must be distinct");
+
+                UnivariatePoly::new(vec![*y * d_inv])
+            })
+            .collect();
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            combinations = combinations
+                .chunks(2)
+                .enumerate()
+                .map(|(i, pair)| match pair {
+                    [left, right] => left * &layer[2 * i + 1] + right * &layer[2 * i],
+                    _ => pair[0].clone(),
+                })
+                .collect();
+        }
+
+        combinations.remove(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SubproductTree;
+    use crate::univariate_polynomial_dense::UnivariatePoly;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn it_builds_the_vanishing_polynomial() {
+        let points: Vec<Fr> = (1..=5).map(Fr::from).collect();
+        let tree = SubproductTree::new(&points);
+
+        let root = tree.root();
+
+        assert_eq!(root.coefficient.len(), 6);
+        for x in points {
+            assert_eq!(root.evaluate(x), Fr::from(0));
+        }
+    }
+
+    #[test]
+    fn it_evaluates_and_interpolates_on_odd_sized_point_sets() {
+        let points: Vec<Fr> = (0..13).map(|i| Fr::from(3 * i + 2)).collect();
+        let poly = UnivariatePoly::new((0..13).map(|i| Fr::from(i * i + 1)).collect());
+        let tree = SubproductTree::new(&points);
+
+        let evals = tree.evaluate(&poly);
+
+        for (x, eval) in points.iter().zip(evals.iter()) {
+            assert_eq!(poly.evaluate(*x), *eval);
+        }
+
+        assert_eq!(tree.interpolate(&evals).coefficient, poly.coefficient);
+    }
+
+    #[test]
+    #[should_panic(expected = "Interpolation points must be distinct")]
+    fn it_rejects_repeated_points() {
+        let points = vec![Fr::from(1), Fr::from(2), Fr::from(1)];
+
+        SubproductTree::new(&points).interpolate(&[Fr::from(1), Fr::from(2), Fr::from(3)]);
+    }
+}
@@ -1,51 +1,146 @@
-use ark_ff::PrimeField;
-use std::ops::{Add, Mul};
+use ark_ff::{FftField, Field, One, PrimeField, Zero};
+use fft::fft_domain::{FftDomain, FftError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
+use crate::{multipoint::SubproductTree, univariate_polynomial_sparse::SparseUnivariatePoly};
+
+/// Products where both factors have at least this many coefficients go through the NTT.
+const FFT_MUL_THRESHOLD: usize = 64;
+
+/// Divisions whose quotient and divisor both reach this length use Newton iteration.
+const NEWTON_DIV_THRESHOLD: usize = 64;
+
+/// From this many points on, multipoint evaluation uses a subproduct tree. On `evaluate many` in
+/// the benchmark, a degree `n - 1` polynomial at `n` points, Horner is still faster at 1024
+/// points and the tree wins from 1536 on, so the switch sits between the two.
+const TREE_EVALUATION_THRESHOLD: usize = 1280;
+
+/// Naive interpolation is cubic, so the subproduct tree takes over much earlier.
+const TREE_INTERPOLATION_THRESHOLD: usize = 8;
+
+/// Number of coefficients each task handles in `evaluate_chunked`.
+const EVALUATION_CHUNK_SIZE: usize = 1 << 12;
+
+/// Coefficients from the constant term up. Constructors and operators return the normalized
+/// form without trailing zeros, in which the zero polynomial has no coefficients at all.
 #[derive(Debug, Clone)]
-pub struct UnivariatePoly<F: PrimeField> {
+pub struct UnivariatePoly<F: FftField> {
     pub coefficient: Vec<F>,
 }
 
-impl<F: PrimeField> UnivariatePoly<F> {
-    pub fn new(coeff: Vec<F>) -> Self {
+impl<F: FftField> UnivariatePoly<F> {
+    /// Drops trailing zero coefficients.
+    pub fn new(mut coeff: Vec<F>) -> Self {
+        while coeff.last() == Some(&F::zero()) {
+            coeff.pop();
+        }
+
         UnivariatePoly { coefficient: coeff }
     }
 
-    fn trim(&mut self) {
-        while self.coefficient.last() == Some(&F::zero()) {
-            self.coefficient.pop();
-        }
+    /// Horner's rule: one multiplication and one addition per coefficient.
+    pub fn evaluate(&self, x: F) -> F {
+        horner(&self.coefficient, x)
     }
 
-    pub fn evaluate(&self, x: F) -> F {
-        self.coefficient
-            .iter()
-            .enumerate()
-            .map(|(index, coeff)| *coeff * x.pow(&[index as u64]))
-            .sum()
+    /// Evaluates at each point with Horner's rule, in parallel across points with the
+    /// `parallel` feature.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        #[cfg(feature = "parallel")]
+        let evaluations = points.par_iter().map(|x| self.evaluate(*x)).collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let evaluations = points.iter().map(|x| self.evaluate(*x)).collect();
+
+        evaluations
     }
 
-    pub fn degree(&mut self) -> usize {
-        self.trim();
+    /// Splits the coefficients into chunks, evaluates each with Horner's rule and recombines
+    /// them as `sum_j chunk_j(x) * x^(j * chunk_size)`. The chunks are independent, so they run
+    /// in parallel with the `parallel` feature, which pays off for polynomials with millions of
+    /// coefficients.
+    pub fn evaluate_chunked(&self, x: F) -> F {
+        #[cfg(feature = "parallel")]
+        let partials: Vec<F> = self
+            .coefficient
+            .par_chunks(EVALUATION_CHUNK_SIZE)
+            .map(|chunk| horner(chunk, x))
+            .collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let partials: Vec<F> = self
+            .coefficient
+            .chunks(EVALUATION_CHUNK_SIZE)
+            .map(|chunk| horner(chunk, x))
+            .collect();
+
+        horner(&partials, x.pow([EVALUATION_CHUNK_SIZE as u64]))
+    }
 
-        self.coefficient.len() - 1
+    /// `None` for the zero polynomial. Skips trailing zeros, so it is also correct for
+    /// coefficient vectors that were edited in place.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficient.iter().rposition(|c| !c.is_zero())
     }
 
-    fn scalar_mul(&self, scalar: F) -> Self {
+    pub fn scalar_mul(&self, scalar: F) -> Self {
         let coefficients = self
             .coefficient
             .iter()
             .map(|point| *point * scalar)
             .collect();
 
-        let mut poly = UnivariatePoly::new(coefficients);
+        UnivariatePoly::new(coefficients)
+    }
 
-        poly.trim();
+    /// Evaluates at every point, one by one for a few points and through a subproduct tree for
+    /// many.
+    pub fn multipoint_evaluate(&self, points: &[F]) -> Vec<F> {
+        if points.len() < TREE_EVALUATION_THRESHOLD {
+            return self.evaluate_many(points);
+        }
 
-        poly
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Returns `self(other(x))`, evaluating `self` with Horner's rule over polynomials.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coefficient
+            .iter()
+            .rev()
+            .fold(UnivariatePoly::zero(), |acc, c| {
+                &acc * other + UnivariatePoly::new(vec![*c])
+            })
+    }
+
+    pub fn derivative(&self) -> Self {
+        UnivariatePoly::new(
+            self.coefficient
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| F::from(i as u64) * c)
+                .collect(),
+        )
     }
 
     pub fn interpolate(points: Vec<(F, F)>) -> UnivariatePoly<F> {
+        if points.len() < TREE_INTERPOLATION_THRESHOLD {
+            return Self::naive_interpolate(points);
+        }
+
+        let (xs, ys): (Vec<F>, Vec<F>) = points.into_iter().unzip();
+
+        SubproductTree::new(&xs).interpolate(&ys)
+    }
+
+    /// Sums the Lagrange basis polynomials, each built as a product of linear factors.
+    fn naive_interpolate(points: Vec<(F, F)>) -> UnivariatePoly<F> {
         let n = points.len();
         let mut result = UnivariatePoly::new(vec![F::zero()]);
 
@@ -61,23 +156,244 @@ impl<F: PrimeField> UnivariatePoly<F> {
 
                     let denominator = x_i - x_j;
 
-                    l_i = l_i * numerator.scalar_mul(F::one() / denominator);
+                    l_i *= numerator.scalar_mul(F::one() / denominator);
                 }
             }
 
-            result = result + l_i.scalar_mul(y_i);
+            result += l_i.scalar_mul(y_i);
         }
 
-        result.trim();
-
         result
     }
+
+    /// Schoolbook multiplication, quadratic in the number of coefficients.
+    pub fn naive_mul(&self, other: &Self) -> Self {
+        if self.coefficient.is_empty() || other.coefficient.is_empty() {
+            return UnivariatePoly::new(vec![]);
+        }
+
+        let mut coeffs = vec![F::zero(); self.coefficient.len() + other.coefficient.len() - 1];
+
+        for (i, a) in self.coefficient.iter().enumerate() {
+            for (j, b) in other.coefficient.iter().enumerate() {
+                coeffs[i + j] += *a * b;
+            }
+        }
+
+        UnivariatePoly::new(coeffs)
+    }
+
+    /// Multiplies by evaluating both factors on a domain that fits the product, multiplying
+    /// pointwise and interpolating back. Fails if the field has no such domain.
+    pub fn fft_mul(&self, other: &Self) -> Result<Self, FftError> {
+        if self.coefficient.is_empty() || other.coefficient.is_empty() {
+            return Ok(UnivariatePoly::new(vec![]));
+        }
+
+        let product_len = self.coefficient.len() + other.coefficient.len() - 1;
+        let domain = FftDomain::new_at_least(product_len)?;
+
+        let mut evals = domain.fft(&self.coefficient);
+
+        for (eval, other_eval) in evals.iter_mut().zip(domain.fft(&other.coefficient)) {
+            *eval *= other_eval;
+        }
+
+        let mut coeffs = domain.ifft(&evals);
+        coeffs.truncate(product_len);
+
+        Ok(UnivariatePoly::new(coeffs))
+    }
+
+    /// Returns `(quotient, remainder)` with `self = quotient * divisor + remainder` and the
+    /// remainder of lower degree than the divisor. Long division is used for small inputs and
+    /// Newton iteration on the reversed polynomials for large ones.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let dividend = UnivariatePoly::new(self.coefficient.clone());
+        let divisor = UnivariatePoly::new(divisor.coefficient.clone());
+
+        if divisor.coefficient.is_empty() {
+            panic!("Cannot divide by the zero polynomial");
+        }
+
+        if dividend.coefficient.len() < divisor.coefficient.len() {
+            return (UnivariatePoly::new(vec![]), dividend);
+        }
+
+        let quotient_len = dividend.coefficient.len() - divisor.coefficient.len() + 1;
+
+        if quotient_len >= NEWTON_DIV_THRESHOLD && divisor.coefficient.len() >= NEWTON_DIV_THRESHOLD
+        {
+            dividend.newton_div_rem(&divisor)
+        } else {
+            dividend.long_div_rem(&divisor)
+        }
+    }
+
+    /// Divides by the vanishing polynomial `Z_H(x) = x^n - 1` of a size `n` domain in linear
+    /// time, as a two-term sparse divisor. Returns `(quotient, remainder)` as `div_rem` does; the
+    /// remainder is zero exactly when `self` vanishes on `H`.
+    pub fn div_by_vanishing(&self, n: usize) -> (Self, Self) {
+        if n == 0 {
+            panic!("Vanishing polynomial needs a non-empty domain");
+        }
+
+        self.div_rem_sparse(&SparseUnivariatePoly::vanishing(n))
+    }
+
+    /// Expects both polynomials trimmed, with `divisor` no longer than `self`.
+    fn long_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_len = divisor.coefficient.len();
+        let leading_inv = divisor.coefficient[divisor_len - 1]
+            .inverse()
+            .expect("Trimmed divisor has a non-zero leading coefficient");
+
+        let mut remainder = self.coefficient.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_len + 1];
+
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_len - 1] * leading_inv;
+            quotient[i] = factor;
+
+            for (j, d) in divisor.coefficient.iter().enumerate() {
+                remainder[i + j] -= factor * d;
+            }
+        }
+
+        remainder.truncate(divisor_len - 1);
+
+        (
+            UnivariatePoly::new(quotient),
+            UnivariatePoly::new(remainder),
+        )
+    }
+
+    /// With `rev(p)` the coefficients of `p` reversed, the quotient satisfies
+    /// `rev(q) = rev(self) * rev(divisor)^-1 mod x^(deg q + 1)`, and the inverse comes from
+    /// Newton iteration, so the whole division costs a few multiplications.
+    /// Expects both polynomials trimmed, with `divisor` no longer than `self`.
+    fn newton_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let quotient_len = self.coefficient.len() - divisor.coefficient.len() + 1;
+
+        let divisor_inv = divisor.reversed().inverse_mod_x_pow(quotient_len);
+        let mut quotient = (self.reversed().mod_x_pow(quotient_len) * divisor_inv)
+            .mod_x_pow(quotient_len)
+            .coefficient;
+
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+
+        let quotient = UnivariatePoly::new(quotient);
+
+        let remainder = self - &(divisor * &quotient);
+
+        (quotient, remainder)
+    }
+
+    /// Returns `g` with `self * g = 1 mod x^k`, doubling the precision of `g` with each step
+    /// `g <- g * (2 - self * g)`. Panics if the constant term is zero.
+    fn inverse_mod_x_pow(&self, k: usize) -> Self {
+        let constant_inv = self
+            .coefficient
+            .first()
+            .and_then(|c| c.inverse())
+            .expect("Constant term must be invertible");
+
+        let mut inverse = UnivariatePoly::new(vec![constant_inv]);
+        let mut precision = 1;
+
+        while precision < k {
+            precision = (2 * precision).min(k);
+
+            let product = (&self.mod_x_pow(precision) * &inverse).mod_x_pow(precision);
+            let correction = UnivariatePoly::new(vec![F::from(2u64)]) - product;
+
+            inverse = (&inverse * &correction).mod_x_pow(precision);
+        }
+
+        inverse
+    }
+
+    fn normalized_len(&self) -> usize {
+        self.degree().map_or(0, |d| d + 1)
+    }
+
+    fn mod_x_pow(&self, k: usize) -> Self {
+        UnivariatePoly::new(self.coefficient.iter().take(k).copied().collect())
+    }
+
+    fn reversed(&self) -> Self {
+        UnivariatePoly::new(self.coefficient.iter().rev().copied().collect())
+    }
 }
 
-impl<F: PrimeField> Add for UnivariatePoly<F> {
-    type Output = Self;
+impl<F: PrimeField> UnivariatePoly<F> {
+    /// Evaluates a base-field polynomial at a point of an extension `E` by
+    /// Horner's rule, adding each coefficient into the base coordinate only.
+    pub fn evaluate_ext<E: Field<BasePrimeField = F>>(&self, x: E) -> E {
+        self.coefficient.iter().rev().fold(E::zero(), |acc, coeff| {
+            acc * x + E::from_base_prime_field(*coeff)
+        })
+    }
+}
+
+fn horner<F: FftField>(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
+
+/// Implements the owned and mixed forms of a binary operator in terms of the `&a op &b` one.
+macro_rules! forward_binop {
+    ($trait:ident, $method:ident) => {
+        impl<F: FftField> $trait for UnivariatePoly<F> {
+            type Output = UnivariatePoly<F>;
+
+            fn $method(self, other: Self) -> UnivariatePoly<F> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<F: FftField> $trait<&UnivariatePoly<F>> for UnivariatePoly<F> {
+            type Output = UnivariatePoly<F>;
 
-    fn add(self, other: Self) -> Self {
+            fn $method(self, other: &UnivariatePoly<F>) -> UnivariatePoly<F> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<F: FftField> $trait<UnivariatePoly<F>> for &UnivariatePoly<F> {
+            type Output = UnivariatePoly<F>;
+
+            fn $method(self, other: UnivariatePoly<F>) -> UnivariatePoly<F> {
+                self.$method(&other)
+            }
+        }
+    };
+}
+
+/// Implements `a op= b` for owned and borrowed `b` in terms of `&a op &b`.
+macro_rules! forward_assign_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<F: FftField> $trait<&UnivariatePoly<F>> for UnivariatePoly<F> {
+            fn $method(&mut self, other: &UnivariatePoly<F>) {
+                *self = &*self $op other;
+            }
+        }
+
+        impl<F: FftField> $trait for UnivariatePoly<F> {
+            fn $method(&mut self, other: Self) {
+                *self = &*self $op &other;
+            }
+        }
+    };
+}
+
+impl<F: FftField> Add<&UnivariatePoly<F>> for &UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    fn add(self, other: &UnivariatePoly<F>) -> UnivariatePoly<F> {
         let mut result = vec![F::zero(); self.coefficient.len().max(other.coefficient.len())];
 
         for (i, &coeff) in self.coefficient.iter().enumerate() {
@@ -92,34 +408,154 @@ impl<F: PrimeField> Add for UnivariatePoly<F> {
     }
 }
 
-impl<F: PrimeField> Mul for UnivariatePoly<F> {
-    type Output = Self;
+impl<F: FftField> Sub<&UnivariatePoly<F>> for &UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
 
-    fn mul(mut self, mut other: Self) -> Self {
-        let mut coeffs = vec![F::zero(); self.degree() + other.degree() + 1];
+    fn sub(self, other: &UnivariatePoly<F>) -> UnivariatePoly<F> {
+        self + &(-other)
+    }
+}
 
-        for (i, a) in self.coefficient.iter().enumerate() {
-            for (j, b) in other.coefficient.iter().enumerate() {
-                coeffs[i + j] += *a * b;
+impl<F: FftField> Mul<&UnivariatePoly<F>> for &UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    /// Uses the NTT once both factors reach `FFT_MUL_THRESHOLD` coefficients, falling back to
+    /// schoolbook multiplication for small inputs or fields without a large enough domain.
+    fn mul(self, other: &UnivariatePoly<F>) -> UnivariatePoly<F> {
+        if self.normalized_len().min(other.normalized_len()) >= FFT_MUL_THRESHOLD {
+            if let Ok(product) = self.fft_mul(other) {
+                return product;
             }
         }
 
-        UnivariatePoly::new(coeffs)
+        self.naive_mul(other)
+    }
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+forward_binop!(Mul, mul);
+
+forward_assign_op!(AddAssign, add_assign, +);
+forward_assign_op!(SubAssign, sub_assign, -);
+forward_assign_op!(MulAssign, mul_assign, *);
+
+impl<F: FftField> Mul<F> for &UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    fn mul(self, scalar: F) -> UnivariatePoly<F> {
+        self.scalar_mul(scalar)
+    }
+}
+
+impl<F: FftField> Mul<F> for UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    fn mul(self, scalar: F) -> UnivariatePoly<F> {
+        self.scalar_mul(scalar)
+    }
+}
+
+impl<F: FftField> MulAssign<F> for UnivariatePoly<F> {
+    fn mul_assign(&mut self, scalar: F) {
+        *self = self.scalar_mul(scalar);
+    }
+}
+
+impl<F: FftField> Neg for &UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    fn neg(self) -> UnivariatePoly<F> {
+        UnivariatePoly::new(self.coefficient.iter().map(|c| -*c).collect())
+    }
+}
+
+impl<F: FftField> Neg for UnivariatePoly<F> {
+    type Output = UnivariatePoly<F>;
+
+    fn neg(self) -> UnivariatePoly<F> {
+        -&self
+    }
+}
+
+/// Polynomials are equal when they agree up to trailing zero coefficients.
+impl<F: FftField> PartialEq for UnivariatePoly<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.coefficient.len().max(other.coefficient.len());
+
+        (0..len).all(|i| {
+            self.coefficient.get(i).copied().unwrap_or(F::zero())
+                == other.coefficient.get(i).copied().unwrap_or(F::zero())
+        })
+    }
+}
+
+impl<F: FftField> Eq for UnivariatePoly<F> {}
+
+impl<F: FftField> Zero for UnivariatePoly<F> {
+    fn zero() -> Self {
+        UnivariatePoly::new(vec![])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficient.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: FftField> One for UnivariatePoly<F> {
+    fn one() -> Self {
+        UnivariatePoly::new(vec![F::one()])
+    }
+}
+
+/// Writes the polynomial from the highest degree down, e.g. `3*x^2 + x + 5`.
+impl<F: FftField> fmt::Display for UnivariatePoly<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self
+            .coefficient
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| match (i, c.is_one()) {
+                (0, _) => format!("{c}"),
+                (1, true) => "x".to_string(),
+                (1, false) => format!("{c}*x"),
+                (_, true) => format!("x^{i}"),
+                (_, false) => format!("{c}*x^{i}"),
+            })
+            .collect();
+
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use ark_bn254::Fq;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::Field;
+    use proptest::prelude::*;
+
+    fn sample_poly(len: usize, seed: u64) -> UnivariatePoly<Fr> {
+        UnivariatePoly::new(
+            (0..len as u64)
+                .map(|i| Fr::from(i * i + seed * i + 1))
+                .collect(),
+        )
+    }
 
     #[test]
     fn it_returns_degree() {
-        let mut poly_1: UnivariatePoly<Fq> = UnivariatePoly {
+        let poly_1: UnivariatePoly<Fq> = UnivariatePoly {
             coefficient: vec![Fq::from(3), Fq::from(4), Fq::from(3)],
         };
 
-        assert!(poly_1.degree() == 2);
+        assert!(poly_1.degree() == Some(2));
     }
 
     #[test]
@@ -194,4 +630,353 @@ mod test {
 
         assert!(new_poly.coefficient == vec![Fq::from(2), Fq::from(2)]);
     }
+
+    #[test]
+    fn it_multiplies_with_fft_above_threshold() {
+        let poly_1 = sample_poly(100, 3);
+        let poly_2 = sample_poly(150, 5);
+
+        let expected = poly_1.naive_mul(&poly_2);
+
+        assert_eq!(
+            poly_1.fft_mul(&poly_2).unwrap().coefficient,
+            expected.coefficient
+        );
+        assert_eq!((poly_1 * poly_2).coefficient, expected.coefficient);
+    }
+
+    #[test]
+    fn it_falls_back_to_naive_mul_without_fft_domain() {
+        // BN254's base field has two-adicity 1, so no domain fits a product of this size.
+        let poly_1 = UnivariatePoly::new((0..100).map(|i| Fq::from(i as u64 + 1)).collect());
+        let poly_2 = UnivariatePoly::new((0..80).map(|i| Fq::from(2 * i as u64 + 3)).collect());
+
+        assert!(poly_1.fft_mul(&poly_2).is_err());
+        assert_eq!(
+            (&poly_1 * &poly_2).coefficient,
+            poly_1.naive_mul(&poly_2).coefficient
+        );
+    }
+
+    #[test]
+    fn it_divides_with_remainder() {
+        // x^3 + 2x^2 + 4 = (x^2 + x - 1)(x + 1) + (x + 5)
+        let dividend =
+            UnivariatePoly::new(vec![Fr::from(4), Fr::from(0), Fr::from(2), Fr::from(1)]);
+        let divisor = UnivariatePoly::new(vec![Fr::from(1), Fr::from(1)]);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert_eq!(
+            quotient.coefficient,
+            vec![Fr::from(-1), Fr::from(1), Fr::from(1)]
+        );
+        assert_eq!(remainder.coefficient, vec![Fr::from(5)]);
+
+        let (quotient, remainder) = divisor.div_rem(&dividend);
+
+        assert!(quotient.coefficient.is_empty());
+        assert_eq!(remainder.coefficient, divisor.coefficient);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by the zero polynomial")]
+    fn it_panics_on_division_by_zero() {
+        let poly = UnivariatePoly::new(vec![Fr::from(1), Fr::from(2)]);
+
+        poly.div_rem(&UnivariatePoly::new(vec![Fr::from(0)]));
+    }
+
+    #[test]
+    fn it_matches_long_division_with_newton_iteration() {
+        let dividend = sample_poly(300, 7);
+        let divisor = sample_poly(90, 11);
+
+        let (quotient, remainder) = dividend.newton_div_rem(&divisor);
+        let (expected_quotient, expected_remainder) = dividend.long_div_rem(&divisor);
+
+        assert_eq!(quotient.coefficient, expected_quotient.coefficient);
+        assert_eq!(remainder.coefficient, expected_remainder.coefficient);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        let reconstructed = divisor * quotient + remainder;
+
+        assert_eq!(reconstructed.coefficient, dividend.coefficient);
+    }
+
+    #[test]
+    fn it_inverts_modulo_a_power_of_x() {
+        let poly = sample_poly(20, 2);
+
+        let inverse = poly.inverse_mod_x_pow(37);
+        let product = (poly * inverse).mod_x_pow(37);
+
+        assert_eq!(product.coefficient, vec![Fr::from(1)]);
+    }
+
+    #[test]
+    fn it_divides_by_vanishing_poly() {
+        let n = 8;
+        let mut vanishing = vec![Fr::from(0); n + 1];
+        vanishing[0] = Fr::from(-1);
+        vanishing[n] = Fr::from(1);
+        let vanishing = UnivariatePoly::new(vanishing);
+
+        let quotient = sample_poly(20, 4);
+        let remainder = sample_poly(5, 9);
+        let dividend = &vanishing * &quotient;
+
+        let (exact_quotient, zero) = dividend.div_by_vanishing(n);
+
+        assert_eq!(exact_quotient.coefficient, quotient.coefficient);
+        assert!(zero.coefficient.is_empty());
+
+        let (q, r) = (dividend + remainder.clone()).div_by_vanishing(n);
+
+        assert_eq!(q.coefficient, quotient.coefficient);
+        assert_eq!(r.coefficient, remainder.coefficient);
+    }
+
+    #[test]
+    fn it_cross_checks_multipoint_evaluation() {
+        let poly = sample_poly(150, 6);
+        let points: Vec<Fr> = (0..100).map(|i| Fr::from(5 * i as u64 + 3)).collect();
+
+        let naive: Vec<Fr> = points.iter().map(|x| poly.evaluate(*x)).collect();
+
+        assert_eq!(SubproductTree::new(&points).evaluate(&poly), naive);
+        assert_eq!(poly.multipoint_evaluate(&points), naive);
+        assert_eq!(
+            SubproductTree::new(&points[..10]).evaluate(&poly),
+            naive[..10]
+        );
+    }
+
+    #[test]
+    fn it_cross_checks_interpolation() {
+        for n in [1, 2, 7, 20, 70] {
+            let points: Vec<(Fr, Fr)> = (0..n)
+                .map(|i| (Fr::from(2 * i as u64 + 1), Fr::from(i * i + 4)))
+                .collect();
+
+            let naive = UnivariatePoly::naive_interpolate(points.clone());
+            let (xs, ys): (Vec<Fr>, Vec<Fr>) = points.iter().copied().unzip();
+
+            assert_eq!(
+                SubproductTree::new(&xs).interpolate(&ys).coefficient,
+                naive.coefficient
+            );
+            assert_eq!(
+                UnivariatePoly::interpolate(points).coefficient,
+                naive.coefficient
+            );
+        }
+    }
+
+    #[test]
+    fn it_subtracts_and_negates() {
+        let poly_1 = UnivariatePoly::new(vec![Fq::from(3), Fq::from(4), Fq::from(3)]);
+        let poly_2 = UnivariatePoly::new(vec![Fq::from(1), Fq::from(4), Fq::from(3)]);
+
+        assert_eq!(&poly_1 - &poly_2, UnivariatePoly::new(vec![Fq::from(2)]));
+        assert_eq!(
+            -poly_2.clone(),
+            UnivariatePoly::new(vec![Fq::from(-1), Fq::from(-4), Fq::from(-3)])
+        );
+        assert!((&poly_1 - &poly_1).is_zero());
+        assert_eq!(poly_1.clone() - &poly_2 + poly_2, poly_1);
+    }
+
+    #[test]
+    fn it_supports_borrowed_and_assigning_operators() {
+        let poly_1 = UnivariatePoly::new(vec![Fq::from(1), Fq::from(2)]);
+        let poly_2 = UnivariatePoly::new(vec![Fq::from(3), Fq::from(0), Fq::from(1)]);
+
+        let mut acc = UnivariatePoly::zero();
+        acc += &poly_1;
+        acc += poly_2.clone();
+        assert_eq!(acc, &poly_1 + &poly_2);
+
+        acc -= &poly_2;
+        assert_eq!(acc, poly_1);
+
+        acc *= &poly_2;
+        assert_eq!(acc, &poly_1 * &poly_2);
+
+        acc *= Fq::from(2);
+        assert_eq!(acc, (&poly_1 * Fq::from(2)) * poly_2);
+    }
+
+    #[test]
+    fn it_compares_ignoring_trailing_zeros() {
+        let poly_1 = UnivariatePoly::new(vec![Fq::from(1), Fq::from(2)]);
+        let poly_2 = UnivariatePoly::new(vec![Fq::from(1), Fq::from(2), Fq::from(0)]);
+
+        assert_eq!(poly_1, poly_2);
+        assert_ne!(poly_1, UnivariatePoly::new(vec![Fq::from(1)]));
+        assert_eq!(
+            UnivariatePoly::<Fq>::zero(),
+            UnivariatePoly::new(vec![Fq::from(0)])
+        );
+        assert_eq!(
+            UnivariatePoly::<Fq>::one(),
+            UnivariatePoly::new(vec![Fq::from(1)])
+        );
+    }
+
+    #[test]
+    fn it_composes_polynomials() {
+        // p(x) = x^2 + 1, q(x) = 2x + 3, p(q(x)) = 4x^2 + 12x + 10
+        let p = UnivariatePoly::new(vec![Fq::from(1), Fq::from(0), Fq::from(1)]);
+        let q = UnivariatePoly::new(vec![Fq::from(3), Fq::from(2)]);
+
+        let composed = p.compose(&q);
+
+        assert_eq!(
+            composed,
+            UnivariatePoly::new(vec![Fq::from(10), Fq::from(12), Fq::from(4)])
+        );
+        assert_eq!(
+            composed.evaluate(Fq::from(5)),
+            p.evaluate(q.evaluate(Fq::from(5)))
+        );
+    }
+
+    #[test]
+    fn it_differentiates() {
+        let poly = UnivariatePoly::new(vec![Fq::from(5), Fq::from(3), Fq::from(0), Fq::from(2)]);
+
+        assert_eq!(
+            poly.derivative(),
+            UnivariatePoly::new(vec![Fq::from(3), Fq::from(0), Fq::from(6)])
+        );
+        assert!(UnivariatePoly::new(vec![Fq::from(7)])
+            .derivative()
+            .is_zero());
+    }
+
+    #[test]
+    fn it_displays_from_highest_degree() {
+        let poly = UnivariatePoly::new(vec![Fq::from(5), Fq::from(1), Fq::from(0), Fq::from(3)]);
+
+        assert_eq!(poly.to_string(), "3*x^3 + x + 5");
+        assert_eq!(UnivariatePoly::<Fq>::zero().to_string(), "0");
+    }
+
+    #[test]
+    fn it_evaluates_many_points_and_in_chunks() {
+        let poly = sample_poly(3 * EVALUATION_CHUNK_SIZE + 17, 5);
+        let x = Fr::from(9);
+
+        let expected: Fr = poly
+            .coefficient
+            .iter()
+            .enumerate()
+            .map(|(i, c)| *c * x.pow([i as u64]))
+            .sum();
+
+        assert_eq!(poly.evaluate(x), expected);
+        assert_eq!(poly.evaluate_chunked(x), expected);
+
+        let points: Vec<Fr> = (0..5).map(|i| Fr::from(i * 13)).collect();
+        let many = poly.evaluate_many(&points);
+
+        for (x, eval) in points.iter().zip(many) {
+            assert_eq!(poly.evaluate_chunked(*x), eval);
+        }
+    }
+
+    #[test]
+    fn it_normalizes_the_zero_polynomial() {
+        let zero = UnivariatePoly::new(vec![Fq::from(0), Fq::from(0)]);
+        let poly = UnivariatePoly::new(vec![Fq::from(3), Fq::from(4)]);
+
+        assert!(zero.coefficient.is_empty());
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.evaluate(Fq::from(5)), Fq::from(0));
+        assert_eq!((&zero * &poly).degree(), None);
+        assert_eq!((&zero + &poly).degree(), Some(1));
+        assert_eq!(UnivariatePoly::<Fq>::interpolate(vec![]), zero);
+        assert_eq!(poly.div_rem(&poly).1.degree(), None);
+    }
+
+    /// Coefficient vectors up to length 80, so some products cross `FFT_MUL_THRESHOLD`, with
+    /// zeros common enough to leave trailing zeros in unnormalized inputs.
+    fn arbitrary_poly() -> impl Strategy<Value = UnivariatePoly<Fr>> {
+        prop::collection::vec(prop_oneof![Just(0u64), any::<u64>()], 0..80).prop_map(|coeffs| {
+            UnivariatePoly {
+                coefficient: coeffs.into_iter().map(Fr::from).collect(),
+            }
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn it_satisfies_additive_group_axioms(
+            a in arbitrary_poly(),
+            b in arbitrary_poly(),
+            c in arbitrary_poly(),
+        ) {
+            prop_assert_eq!(&a + &b, &b + &a);
+            prop_assert_eq!(&(&a + &b) + &c, &a + &(&b + &c));
+            prop_assert_eq!(&a + &UnivariatePoly::zero(), a.clone());
+            prop_assert!((&a - &a).is_zero());
+            prop_assert_eq!(-(-a.clone()), a);
+        }
+
+        #[test]
+        fn it_satisfies_multiplicative_axioms(
+            a in arbitrary_poly(),
+            b in arbitrary_poly(),
+            c in arbitrary_poly(),
+        ) {
+            prop_assert_eq!(&a * &b, &b * &a);
+            prop_assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            prop_assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+            prop_assert_eq!(&a * &UnivariatePoly::one(), a.clone());
+            prop_assert!((&a * &UnivariatePoly::zero()).is_zero());
+        }
+
+        #[test]
+        fn it_adds_degrees_when_multiplying(a in arbitrary_poly(), b in arbitrary_poly()) {
+            let expected = match (a.degree(), b.degree()) {
+                (Some(d_a), Some(d_b)) => Some(d_a + d_b),
+                _ => None,
+            };
+
+            prop_assert_eq!((&a * &b).degree(), expected);
+        }
+
+        #[test]
+        fn it_divides_with_a_smaller_remainder(a in arbitrary_poly(), b in arbitrary_poly()) {
+            prop_assume!(!b.is_zero());
+
+            let (quotient, remainder) = a.div_rem(&b);
+
+            prop_assert_eq!(&(&quotient * &b) + &remainder, a);
+            prop_assert!(remainder.degree() < b.degree());
+        }
+    }
+
+    #[test]
+    fn it_evaluates_at_extension_points() {
+        use ark_bn254::{Fq, Fq2};
+
+        let coefficients: Vec<Fq> = [5u64, 0, 7, 3].map(Fq::from).to_vec();
+        let poly = UnivariatePoly::new(coefficients.clone());
+        let lifted = UnivariatePoly::new(
+            coefficients
+                .into_iter()
+                .map(Fq2::from_base_prime_field)
+                .collect(),
+        );
+
+        let x = Fq2::new(Fq::from(4), Fq::from(9));
+        assert_eq!(poly.evaluate_ext(x), lifted.evaluate(x));
+
+        let product = &lifted * &lifted;
+        assert_eq!(product.evaluate(x), lifted.evaluate(x).square());
+    }
 }
@@ -0,0 +1,193 @@
+use ark_ff::{batch_inversion, FftField};
+use fft::fft_domain::{FftDomain, FftError};
+
+use crate::univariate_polynomial_dense::UnivariatePoly;
+
+/// Interpolation points with their barycentric weights `w_i = 1 / prod_{j != i} (x_i - x_j)`.
+/// The weights depend only on the points, so one domain serves every polynomial given by its
+/// values there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarycentricDomain<F: FftField> {
+    pub points: Vec<F>,
+    pub weights: Vec<F>,
+}
+
+impl<F: FftField> BarycentricDomain<F> {
+    /// The points `{0, 1, ..., degree}`, where `w_i = (-1)^(degree - i) / (i! (degree - i)!)`.
+    pub fn integers(degree: usize) -> Self {
+        let mut factorials = vec![F::one(); degree + 1];
+
+        for i in 1..=degree {
+            factorials[i] = factorials[i - 1] * F::from(i as u64);
+        }
+
+        let mut weights: Vec<F> = (0..=degree)
+            .map(|i| {
+                let denominator = factorials[i] * factorials[degree - i];
+
+                if (degree - i).is_multiple_of(2) {
+                    denominator
+                } else {
+                    -denominator
+                }
+            })
+            .collect();
+
+        batch_inversion(&mut weights);
+
+        BarycentricDomain {
+            points: (0..=degree).map(|i| F::from(i as u64)).collect(),
+            weights,
+        }
+    }
+
+    /// The subgroup `{1, w, ..., w^(size - 1)}`, where `w_i = w^i / size`.
+    pub fn roots_of_unity(size: usize) -> Result<Self, FftError> {
+        let domain = FftDomain::new(size)?;
+        let points = domain.elements();
+
+        Ok(BarycentricDomain {
+            weights: points.iter().map(|x| *x * domain.size_inv).collect(),
+            points,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Evaluates at `x` the polynomial taking `evaluation[i]` at the `i`-th point, with the
+    /// barycentric formula `sum (w_i y_i / (x - x_i)) / sum (w_i / (x - x_i))`. Costs one field
+    /// inversion and `O(len)` multiplications.
+    pub fn evaluate(&self, evaluation: &[F], x: F) -> F {
+        assert_eq!(
+            evaluation.len(),
+            self.len(),
+            "Need exactly one evaluation per domain point"
+        );
+
+        if let Some(i) = self.points.iter().position(|p| *p == x) {
+            return evaluation[i];
+        }
+
+        let mut inverse_differences: Vec<F> = self.points.iter().map(|p| x - p).collect();
+
+        batch_inversion(&mut inverse_differences);
+
+        let mut numerator = F::zero();
+        let mut denominator = F::zero();
+
+        for ((w, d), y) in self
+            .weights
+            .iter()
+            .zip(inverse_differences.iter())
+            .zip(evaluation.iter())
+        {
+            let term = *w * d;
+            numerator += term * y;
+            denominator += term;
+        }
+
+        numerator / denominator
+    }
+}
+
+/// A univariate polynomial of degree below the domain size, stored as its values on the domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnivariateEvalPoly<F: FftField> {
+    pub evaluation: Vec<F>,
+    pub domain: BarycentricDomain<F>,
+}
+
+impl<F: FftField> UnivariateEvalPoly<F> {
+    pub fn new(evaluation: Vec<F>, domain: BarycentricDomain<F>) -> Self {
+        assert_eq!(
+            evaluation.len(),
+            domain.len(),
+            "Need exactly one evaluation per domain point"
+        );
+
+        UnivariateEvalPoly { evaluation, domain }
+    }
+
+    /// Takes `evaluation[i]` to be the value at `i`, the form sum-check round polynomials come in.
+    pub fn from_integer_evaluations(evaluation: Vec<F>) -> Self {
+        assert!(!evaluation.is_empty(), "Need at least one evaluation");
+
+        let domain = BarycentricDomain::integers(evaluation.len() - 1);
+
+        UnivariateEvalPoly { evaluation, domain }
+    }
+
+    pub fn evaluate(&self, x: F) -> F {
+        self.domain.evaluate(&self.evaluation, x)
+    }
+
+    pub fn to_coefficients(&self) -> UnivariatePoly<F> {
+        UnivariatePoly::interpolate(
+            self.domain
+                .points
+                .iter()
+                .copied()
+                .zip(self.evaluation.iter().copied())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BarycentricDomain, UnivariateEvalPoly};
+    use crate::univariate_polynomial_dense::UnivariatePoly;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn it_matches_explicit_weights_on_integers() {
+        let domain = BarycentricDomain::<Fr>::integers(3);
+
+        for (i, w) in domain.weights.iter().enumerate() {
+            let product: Fr = (0..4)
+                .filter(|j| *j != i)
+                .map(|j| Fr::from(i as u64) - Fr::from(j as u64))
+                .product();
+
+            assert_eq!(*w * product, Fr::from(1));
+        }
+    }
+
+    #[test]
+    fn it_evaluates_without_interpolating() {
+        let poly = UnivariatePoly::new(vec![Fr::from(7), Fr::from(0), Fr::from(3), Fr::from(2)]);
+        let evaluation: Vec<Fr> = (0..4).map(|i| poly.evaluate(Fr::from(i))).collect();
+
+        let eval_poly = UnivariateEvalPoly::from_integer_evaluations(evaluation);
+
+        for x in [Fr::from(2), Fr::from(11), Fr::from(-5)] {
+            assert_eq!(eval_poly.evaluate(x), poly.evaluate(x));
+        }
+        assert_eq!(eval_poly.to_coefficients(), poly);
+    }
+
+    #[test]
+    fn it_evaluates_over_roots_of_unity() {
+        let poly = UnivariatePoly::new((1..=8).map(|i| Fr::from(i * i)).collect());
+        let domain = BarycentricDomain::roots_of_unity(8).unwrap();
+        let evaluation: Vec<Fr> = domain.points.iter().map(|x| poly.evaluate(*x)).collect();
+
+        let eval_poly = UnivariateEvalPoly::new(evaluation, domain.clone());
+
+        assert_eq!(
+            eval_poly.evaluate(Fr::from(123)),
+            poly.evaluate(Fr::from(123))
+        );
+        assert_eq!(
+            eval_poly.evaluate(domain.points[5]),
+            poly.evaluate(domain.points[5])
+        );
+        assert!(BarycentricDomain::<Fr>::roots_of_unity(7).is_err());
+    }
+}
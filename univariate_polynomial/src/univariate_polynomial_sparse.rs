@@ -0,0 +1,249 @@
+use ark_ff::FftField;
+use std::ops::{Add, Mul, Neg};
+
+use crate::univariate_polynomial_dense::UnivariatePoly;
+
+/// A univariate polynomial stored as its non-zero `(degree, coefficient)` terms, sorted by
+/// degree. Suits polynomials like `x^n - 1` whose dense form is almost all zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseUnivariatePoly<F: FftField> {
+    pub terms: Vec<(usize, F)>,
+}
+
+impl<F: FftField> SparseUnivariatePoly<F> {
+    /// Sorts the terms, merges repeated degrees and drops zero coefficients.
+    pub fn new(mut terms: Vec<(usize, F)>) -> Self {
+        terms.sort_by_key(|(degree, _)| *degree);
+
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+
+        for (degree, coeff) in terms {
+            match merged.last_mut() {
+                Some((last_degree, last_coeff)) if *last_degree == degree => *last_coeff += coeff,
+                _ => merged.push((degree, coeff)),
+            }
+        }
+
+        merged.retain(|(_, coeff)| !coeff.is_zero());
+
+        SparseUnivariatePoly { terms: merged }
+    }
+
+    /// The vanishing polynomial `x^n - 1` of the size `n` multiplicative subgroup.
+    pub fn vanishing(n: usize) -> Self {
+        SparseUnivariatePoly::new(vec![(0, -F::one()), (n, F::one())])
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.last().map(|(degree, _)| *degree)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Walks the terms in order, raising `x` only by the gap between consecutive degrees.
+    pub fn evaluate(&self, x: F) -> F {
+        let mut result = F::zero();
+        let mut power = F::one();
+        let mut power_degree = 0;
+
+        for (degree, coeff) in &self.terms {
+            power *= x.pow([(degree - power_degree) as u64]);
+            power_degree = *degree;
+            result += power * coeff;
+        }
+
+        result
+    }
+
+    pub fn to_dense(&self) -> UnivariatePoly<F> {
+        let mut coefficients = vec![F::zero(); self.degree().map_or(0, |d| d + 1)];
+
+        for (degree, coeff) in &self.terms {
+            coefficients[*degree] = *coeff;
+        }
+
+        UnivariatePoly::new(coefficients)
+    }
+
+    pub fn from_dense(poly: &UnivariatePoly<F>) -> Self {
+        SparseUnivariatePoly::new(poly.coefficient.iter().copied().enumerate().collect())
+    }
+}
+
+impl<F: FftField> UnivariatePoly<F> {
+    /// Long division by a sparse divisor with `k` terms, in `O(len * k)` instead of the
+    /// `O(len * deg)` of dense long division. Returns `(quotient, remainder)` as `div_rem` does.
+    pub fn div_rem_sparse(&self, divisor: &SparseUnivariatePoly<F>) -> (Self, Self) {
+        let (divisor_degree, leading) = *divisor
+            .terms
+            .last()
+            .expect("Cannot divide by the zero polynomial");
+
+        if self.coefficient.len() <= divisor_degree {
+            return (
+                UnivariatePoly::new(vec![]),
+                UnivariatePoly::new(self.coefficient.clone()),
+            );
+        }
+
+        let mut remainder = self.coefficient.clone();
+
+        let leading_inv = leading.inverse().expect("Leading coefficient is non-zero");
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_degree];
+
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree] * leading_inv;
+            quotient[i] = factor;
+
+            for (degree, coeff) in &divisor.terms {
+                remainder[i + degree] -= factor * coeff;
+            }
+        }
+
+        remainder.truncate(divisor_degree);
+
+        (
+            UnivariatePoly::new(quotient),
+            UnivariatePoly::new(remainder),
+        )
+    }
+}
+
+impl<F: FftField> Add<&SparseUnivariatePoly<F>> for &SparseUnivariatePoly<F> {
+    type Output = SparseUnivariatePoly<F>;
+
+    fn add(self, other: &SparseUnivariatePoly<F>) -> SparseUnivariatePoly<F> {
+        SparseUnivariatePoly::new(self.terms.iter().chain(&other.terms).copied().collect())
+    }
+}
+
+impl<F: FftField> Add for SparseUnivariatePoly<F> {
+    type Output = SparseUnivariatePoly<F>;
+
+    fn add(self, other: Self) -> SparseUnivariatePoly<F> {
+        &self + &other
+    }
+}
+
+impl<F: FftField> Mul<&SparseUnivariatePoly<F>> for &SparseUnivariatePoly<F> {
+    type Output = SparseUnivariatePoly<F>;
+
+    fn mul(self, other: &SparseUnivariatePoly<F>) -> SparseUnivariatePoly<F> {
+        let terms = self
+            .terms
+            .iter()
+            .flat_map(|(d_1, c_1)| {
+                other
+                    .terms
+                    .iter()
+                    .map(move |(d_2, c_2)| (d_1 + d_2, *c_1 * c_2))
+            })
+            .collect();
+
+        SparseUnivariatePoly::new(terms)
+    }
+}
+
+impl<F: FftField> Mul for SparseUnivariatePoly<F> {
+    type Output = SparseUnivariatePoly<F>;
+
+    fn mul(self, other: Self) -> SparseUnivariatePoly<F> {
+        &self * &other
+    }
+}
+
+impl<F: FftField> Neg for SparseUnivariatePoly<F> {
+    type Output = SparseUnivariatePoly<F>;
+
+    fn neg(self) -> SparseUnivariatePoly<F> {
+        SparseUnivariatePoly {
+            terms: self.terms.into_iter().map(|(d, c)| (d, -c)).collect(),
+        }
+    }
+}
+
+impl<F: FftField> From<SparseUnivariatePoly<F>> for UnivariatePoly<F> {
+    fn from(poly: SparseUnivariatePoly<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: FftField> From<UnivariatePoly<F>> for SparseUnivariatePoly<F> {
+    fn from(poly: UnivariatePoly<F>) -> Self {
+        SparseUnivariatePoly::from_dense(&poly)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SparseUnivariatePoly;
+    use crate::univariate_polynomial_dense::UnivariatePoly;
+    use ark_bn254::Fr;
+    use ark_ff::{FftField, Field};
+
+    fn sample_sparse() -> SparseUnivariatePoly<Fr> {
+        SparseUnivariatePoly::new(vec![(40, Fr::from(3)), (0, Fr::from(5)), (7, Fr::from(-2))])
+    }
+
+    #[test]
+    fn it_normalizes_terms() {
+        let poly = SparseUnivariatePoly::new(vec![
+            (5, Fr::from(2)),
+            (1, Fr::from(1)),
+            (5, Fr::from(3)),
+            (3, Fr::from(4)),
+            (3, Fr::from(-4)),
+        ]);
+
+        assert_eq!(poly.terms, vec![(1, Fr::from(1)), (5, Fr::from(5))]);
+        assert_eq!(poly.degree(), Some(5));
+        assert_eq!(
+            SparseUnivariatePoly::<Fr>::new(vec![(2, Fr::from(0))]).degree(),
+            None
+        );
+    }
+
+    #[test]
+    fn it_agrees_with_the_dense_form() {
+        let sparse = sample_sparse();
+        let other = SparseUnivariatePoly::new(vec![(3, Fr::from(1)), (7, Fr::from(2))]);
+        let dense = sparse.to_dense();
+        let x = Fr::from(6);
+
+        assert_eq!(sparse.evaluate(x), dense.evaluate(x));
+        assert_eq!(SparseUnivariatePoly::from_dense(&dense), sparse);
+        assert_eq!((&sparse + &other).to_dense(), &dense + &other.to_dense());
+        assert_eq!((&sparse * &other).to_dense(), &dense * &other.to_dense());
+        assert_eq!(UnivariatePoly::from(-sparse.clone()), -dense);
+    }
+
+    #[test]
+    fn it_evaluates_large_vanishing_polynomials() {
+        let n = 1 << 20;
+        let vanishing = SparseUnivariatePoly::<Fr>::vanishing(n);
+        let root = Fr::get_root_of_unity(n as u64).unwrap();
+
+        assert_eq!(vanishing.terms.len(), 2);
+        assert_eq!(vanishing.evaluate(root.pow([12345])), Fr::from(0));
+        assert_ne!(vanishing.evaluate(Fr::from(2)), Fr::from(0));
+    }
+
+    #[test]
+    fn it_divides_dense_by_sparse() {
+        let divisor = sample_sparse();
+        let dividend = UnivariatePoly::new((0..300).map(|i| Fr::from(i * i + 1)).collect());
+
+        let (quotient, remainder) = dividend.div_rem_sparse(&divisor);
+        let (expected_quotient, expected_remainder) = dividend.div_rem(&divisor.to_dense());
+
+        assert_eq!(quotient, expected_quotient);
+        assert_eq!(remainder, expected_remainder);
+
+        let (quotient, remainder) = divisor.to_dense().div_rem_sparse(&divisor);
+
+        assert_eq!(quotient.coefficient, vec![Fr::from(1)]);
+        assert!(remainder.coefficient.is_empty());
+    }
+}